@@ -0,0 +1,428 @@
+use aoc_core::{column_of, parse_field, Error, Result, Solution};
+pub use num_bigint::BigInt;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, ToPrimitive, Zero};
+use std::any::type_name;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::io::BufRead;
+use std::path::Path;
+use std::str::FromStr;
+use tracing::debug;
+
+pub mod metrics;
+
+pub struct Day1;
+
+/// What the lists can hold: any integer type with checked arithmetic, such as
+/// `i64`, `u64`, `i128` or, for sums no fixed width can hold, [`BigInt`].
+pub trait Number:
+    Clone
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + FromStr<Err: Display>
+    + Zero
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + FromPrimitive
+    + ToPrimitive
+{
+}
+
+impl<T> Number for T where
+    T: Clone
+        + Ord
+        + Hash
+        + Debug
+        + Display
+        + FromStr<Err: Display>
+        + Zero
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + FromPrimitive
+        + ToPrimitive
+{
+}
+
+pub(crate) fn overflow<T>(what: &str) -> Error {
+    Error::puzzle(format!("{} overflows {}", what, type_name::<T>()))
+}
+
+/// Lists of numbers, read a row per line and kept by column. Every row has
+/// the same number of values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns<T = i64> {
+    columns: Vec<Vec<T>>,
+}
+
+impl<T> Default for Columns<T> {
+    fn default() -> Self {
+        Columns {
+            columns: Vec::new(),
+        }
+    }
+}
+
+impl<T> Columns<T> {
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Number of rows.
+    pub fn len(&self) -> usize {
+        self.columns.first().map_or(0, Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The column at `index`, counting from 0. An input with no rows has no
+    /// width to check against, so any column of it is empty, and the answers
+    /// to an empty input are 0.
+    pub fn column(&self, index: usize) -> Result<&[T]> {
+        if self.columns.is_empty() {
+            return Ok(&[]);
+        }
+        self.columns.get(index).map(Vec::as_slice).ok_or_else(|| {
+            Error::puzzle(format!(
+                "no column {} in an input with {} columns",
+                index + 1,
+                self.width()
+            ))
+        })
+    }
+
+    /// The columns at `left` and `right`, to compare with each other.
+    pub fn pair(&self, left: usize, right: usize) -> Result<(&[T], &[T])> {
+        Ok((self.column(left)?, self.column(right)?))
+    }
+}
+
+/// Splits `line` into values separated by any mix of whitespace and commas.
+fn fields(line: &str) -> impl Iterator<Item = &str> {
+    line.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|field| !field.is_empty())
+}
+
+/// Parses line `i` into its values, checking it has `width` of them. A width
+/// of 0 is set from the line. Blank lines give `None`.
+fn parse_row<T: Number>(i: usize, line: &str, width: &mut usize) -> Result<Option<Vec<T>>> {
+    let row: Vec<&str> = fields(line).collect();
+    if row.is_empty() {
+        return Ok(None);
+    }
+    if *width == 0 {
+        *width = row.len();
+    }
+    if row.len() != *width {
+        // Point at the first extra value, or just past the end of a short row
+        let column = match row.get(*width) {
+            Some(extra) => column_of(line, extra),
+            None => line.trim_end().chars().count() + 1,
+        };
+        return Err(Error::parse(
+            i + 1,
+            column,
+            format!("expected {} values, found {}", width, row.len()),
+        ));
+    }
+    row.into_iter()
+        .map(|field| parse_field(i, line, field))
+        .collect::<Result<_>>()
+        .map(Some)
+}
+
+/// Reads rows of numbers. The first row sets the number of columns; blank
+/// lines are skipped.
+pub fn read_columns<T: Number>(contents: &str) -> Result<Columns<T>> {
+    let mut columns: Vec<Vec<T>> = Vec::new();
+    let mut width = 0;
+
+    for (i, line) in contents.lines().enumerate() {
+        let Some(row) = parse_row(i, line, &mut width)? else {
+            continue;
+        };
+        if columns.is_empty() {
+            columns = (0..width).map(|_| Vec::new()).collect();
+        }
+        for (column, value) in columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+    }
+
+    Ok(Columns { columns })
+}
+
+/// Sum of the distances between the columns' values, paired off smallest to
+/// largest.
+pub fn total_distance<T: Number>(columns: &Columns<T>, left: usize, right: usize) -> Result<T> {
+    let (column1, column2) = columns.pair(left, right)?;
+    let mut column1 = column1.to_vec();
+    let mut column2 = column2.to_vec();
+    column1.sort();
+    column2.sort();
+
+    column1
+        .iter()
+        .zip(column2.iter())
+        .try_fold(T::zero(), |sum, (a, b)| {
+            // Subtract the smaller from the larger so unsigned types work too
+            let distance = if a >= b {
+                a.checked_sub(b)
+            } else {
+                b.checked_sub(a)
+            }
+            .ok_or_else(|| overflow::<T>("distance"))?;
+            sum.checked_add(&distance)
+                .ok_or_else(|| overflow::<T>("total distance"))
+        })
+}
+
+/// Sum of each value in `left` times the number of times it appears in
+/// `right`.
+pub fn similarity_score<T: Number>(columns: &Columns<T>, left: usize, right: usize) -> Result<T> {
+    let (column1, column2) = columns.pair(left, right)?;
+
+    // Count occurrences in column2
+    let mut counter: HashMap<&T, usize> = HashMap::new();
+    for num in column2.iter() {
+        *counter.entry(num).or_insert(0) += 1;
+    }
+
+    // For each number in column1, multiply it by its count in column2
+    let result: Vec<T> = column1
+        .iter()
+        .map(|num| {
+            let count = counter.get(num).copied().unwrap_or(0);
+            T::from_usize(count)
+                .and_then(|count| num.checked_mul(&count))
+                .ok_or_else(|| overflow::<T>("similarity score"))
+        })
+        .collect::<Result<_>>()?;
+
+    debug!(?result, "similarity scores");
+    result.iter().try_fold(T::zero(), |sum, score| {
+        sum.checked_add(score)
+            .ok_or_else(|| overflow::<T>("similarity score"))
+    })
+}
+
+/// [`similarity_score`] of rows read from `reader` one at a time, in a single
+/// pass, keeping only a count per distinct value of each column. `path` names
+/// the input in errors.
+pub fn stream_similarity_score<T: Number>(
+    mut reader: impl BufRead,
+    path: &Path,
+    left: usize,
+    right: usize,
+) -> Result<T> {
+    let mut counts: HashMap<T, (usize, usize)> = HashMap::new();
+    let mut score = T::zero();
+    let mut width = 0;
+    let mut line = String::new();
+
+    for i in 0.. {
+        line.clear();
+        let read = reader.read_line(&mut line).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        if read == 0 {
+            break;
+        }
+        let line = line.trim_end_matches(['\n', '\r']);
+        let Some(row) = parse_row::<T>(i, line, &mut width)? else {
+            continue;
+        };
+        if left.max(right) >= width {
+            return Err(Error::puzzle(format!(
+                "no column {} in an input with {} columns",
+                left.max(right) + 1,
+                width
+            )));
+        }
+
+        // Each new value on one side scores once for every match already seen
+        // on the other, so the total is the same as counting everything first
+        let values = [(&row[left], true), (&row[right], false)];
+        for (value, is_left) in values {
+            let (seen_left, seen_right) = counts.entry(value.clone()).or_default();
+            let matches = if is_left {
+                *seen_left += 1;
+                *seen_right
+            } else {
+                *seen_right += 1;
+                *seen_left
+            };
+            score = T::from_usize(matches)
+                .and_then(|matches| value.checked_mul(&matches))
+                .and_then(|gained| score.checked_add(&gained))
+                .ok_or_else(|| overflow::<T>("similarity score"))?;
+        }
+    }
+
+    Ok(score)
+}
+
+impl Solution for Day1 {
+    type Input = Columns<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        read_columns(input)
+    }
+
+    fn part_one(&self, columns: &Self::Input) -> Result<i64> {
+        total_distance(columns, 0, 1)
+    }
+
+    fn part_two(&self, columns: &Self::Input) -> Result<i64> {
+        similarity_score(columns, 0, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(input: &str) -> (usize, usize, String) {
+        match read_columns::<i64>(input) {
+            Err(Error::Parse {
+                line,
+                column,
+                message,
+            }) => (line, column, message),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn any_mix_of_separators_and_columns() {
+        let columns = read_columns("3   4\n4\t3\n2, 5\n1 ,3\n\n3,9\n3 \t 3\n").unwrap();
+        assert_eq!((columns.width(), columns.len()), (2, 6));
+        assert_eq!(columns.column(0).unwrap(), [3, 4, 2, 1, 3, 3]);
+        assert_eq!(columns.column(1).unwrap(), [4, 3, 5, 3, 9, 3]);
+        assert_eq!(Day1.part_one(&columns).unwrap(), 11);
+        assert_eq!(Day1.part_two(&columns).unwrap(), 31);
+
+        let columns: Columns<i64> = read_columns("1 10 3\n2 20 4\n").unwrap();
+        assert_eq!(columns.width(), 3);
+        assert_eq!(total_distance(&columns, 0, 2).unwrap(), 4);
+        assert_eq!(similarity_score(&columns, 2, 1).unwrap(), 0);
+        assert_eq!(
+            total_distance(&columns, 0, 3).unwrap_err().to_string(),
+            "no column 4 in an input with 3 columns"
+        );
+    }
+
+    #[test]
+    fn empty_inputs_answer_0() {
+        for input in ["", "\n\n"] {
+            let columns = read_columns::<i64>(input).unwrap();
+            assert!(columns.is_empty());
+            assert_eq!(columns.column(5).unwrap(), []);
+            assert_eq!(Day1.part_one(&columns).unwrap(), 0);
+            assert_eq!(Day1.part_two(&columns).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn malformed_rows_name_the_line_and_column() {
+        assert_eq!(
+            parse_error("1 2\n3 x4\n"),
+            (
+                2,
+                3,
+                "invalid value \"x4\": invalid digit found in string".to_string()
+            )
+        );
+        assert_eq!(
+            parse_error("1 2\n\n3\n"),
+            (3, 2, "expected 2 values, found 1".to_string())
+        );
+        assert_eq!(
+            parse_error("1 2\n3 4 5\n"),
+            (2, 5, "expected 2 values, found 3".to_string())
+        );
+    }
+
+    #[test]
+    fn any_integer_type_gives_the_same_answers() {
+        let sample = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let columns: Columns<u64> = read_columns(sample).unwrap();
+        assert_eq!(total_distance(&columns, 0, 1).unwrap(), 11);
+        assert_eq!(similarity_score(&columns, 0, 1).unwrap(), 31);
+        let columns: Columns<i128> = read_columns(sample).unwrap();
+        assert_eq!(total_distance(&columns, 0, 1).unwrap(), 11);
+        let columns: Columns<BigInt> = read_columns(sample).unwrap();
+        assert_eq!(similarity_score(&columns, 0, 1).unwrap(), BigInt::from(31));
+
+        assert_eq!(
+            read_columns::<u64>("1 -2\n").unwrap_err().to_string(),
+            "parse error at line 1, column 3: invalid value \"-2\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn overflow_is_an_error_unless_the_type_is_unbounded() {
+        let extremes = "9223372036854775807 -9223372036854775808\n";
+        let columns: Columns<i64> = read_columns(extremes).unwrap();
+        assert_eq!(
+            total_distance(&columns, 0, 1).unwrap_err().to_string(),
+            "distance overflows i64"
+        );
+        let columns: Columns<i128> = read_columns(extremes).unwrap();
+        assert_eq!(
+            total_distance(&columns, 0, 1).unwrap(),
+            i128::from(u64::MAX)
+        );
+
+        let repeated = "18446744073709551615 18446744073709551615\n1 18446744073709551615\n";
+        let columns: Columns<u64> = read_columns(repeated).unwrap();
+        assert_eq!(
+            similarity_score(&columns, 0, 1).unwrap_err().to_string(),
+            "similarity score overflows u64"
+        );
+        let columns: Columns<BigInt> = read_columns(repeated).unwrap();
+        assert_eq!(
+            similarity_score(&columns, 0, 1).unwrap(),
+            BigInt::from(u64::MAX) * 2
+        );
+    }
+
+    #[test]
+    fn streaming_similarity_matches_the_loaded_score() {
+        let input = "3   4\n4   3\n\n2   5\r\n1   3\n3   9\n3   3";
+        let columns: Columns<i64> = read_columns(input).unwrap();
+        let stream = |input: &str, left, right| {
+            stream_similarity_score::<i64>(input.as_bytes(), Path::new("list"), left, right)
+        };
+        assert_eq!(stream(input, 0, 1).unwrap(), 31);
+        assert_eq!(
+            stream(input, 1, 0).unwrap(),
+            similarity_score(&columns, 1, 0).unwrap()
+        );
+        assert_eq!(stream("", 0, 1).unwrap(), 0);
+
+        assert_eq!(
+            stream("1 2\n3 4\n5 x\n", 0, 1).unwrap_err().to_string(),
+            "parse error at line 3, column 3: invalid value \"x\": invalid digit found in string"
+        );
+        assert_eq!(
+            stream("1 2\n", 0, 2).unwrap_err().to_string(),
+            "no column 3 in an input with 2 columns"
+        );
+        let repeated = "9223372036854775807 9223372036854775807\n";
+        assert_eq!(
+            stream(&repeated.repeat(2), 0, 1).unwrap_err().to_string(),
+            "similarity score overflows i64"
+        );
+    }
+}
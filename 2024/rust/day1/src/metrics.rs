@@ -0,0 +1,204 @@
+//! Ways to compare two columns besides the puzzle's, for using the list parser
+//! on other data.
+
+use crate::{overflow, Columns, Number};
+use aoc_core::Result;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// How two columns compare.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metrics<T> {
+    /// Sum of squared differences between values paired off smallest to
+    /// largest.
+    pub squared_distance: T,
+    /// Kendall's tau-b between the rows' two values.
+    pub kendall_tau: Option<f64>,
+    /// Spearman's rank correlation between the rows' two values.
+    pub spearman: Option<f64>,
+    /// Jaccard index of the columns as multisets.
+    pub jaccard: Option<f64>,
+    /// Earth mover's distance between the columns' distributions of values.
+    pub earth_movers_distance: Option<f64>,
+}
+
+pub fn metrics<T: Number>(columns: &Columns<T>, left: usize, right: usize) -> Result<Metrics<T>> {
+    let (a, b) = columns.pair(left, right)?;
+    Ok(Metrics {
+        squared_distance: squared_distance(a, b)?,
+        kendall_tau: kendall_tau(a, b),
+        spearman: spearman(a, b),
+        jaccard: jaccard(a, b),
+        earth_movers_distance: earth_movers_distance(a, b),
+    })
+}
+
+fn float<T: Number>(value: &T) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+/// Sum of squared differences between `a` and `b` paired off smallest to
+/// largest, like the puzzle's total distance.
+pub fn squared_distance<T: Number>(a: &[T], b: &[T]) -> Result<T> {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort();
+    b.sort();
+
+    a.iter().zip(&b).try_fold(T::zero(), |sum, (a, b)| {
+        let distance = if a >= b {
+            a.checked_sub(b)
+        } else {
+            b.checked_sub(a)
+        };
+        distance
+            .and_then(|d| d.checked_mul(&d))
+            .and_then(|square| sum.checked_add(&square))
+            .ok_or_else(|| overflow::<T>("squared distance"))
+    })
+}
+
+/// Kendall's tau-b of the pairs `(a[i], b[i])`, which accounts for ties.
+/// `None` if either side is constant. Compares every pair of rows, so it takes
+/// time quadratic in their number.
+pub fn kendall_tau<T: Ord>(a: &[T], b: &[T]) -> Option<f64> {
+    let n = a.len().min(b.len());
+    let (mut concordant, mut discordant) = (0i64, 0i64);
+    let (mut tied_a, mut tied_b) = (0i64, 0i64);
+    for i in 0..n {
+        for j in i + 1..n {
+            match (a[i].cmp(&a[j]), b[i].cmp(&b[j])) {
+                (Ordering::Equal, Ordering::Equal) => {
+                    tied_a += 1;
+                    tied_b += 1;
+                }
+                (Ordering::Equal, _) => tied_a += 1,
+                (_, Ordering::Equal) => tied_b += 1,
+                (x, y) if x == y => concordant += 1,
+                _ => discordant += 1,
+            }
+        }
+    }
+    let pairs = (n * n.saturating_sub(1) / 2) as i64;
+    let denominator = (((pairs - tied_a) as f64) * ((pairs - tied_b) as f64)).sqrt();
+    (denominator > 0.0).then(|| (concordant - discordant) as f64 / denominator)
+}
+
+/// 1-based ranks of `values`, with tied values sharing the average of their
+/// ranks.
+fn ranks<T: Ord>(values: &[T]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&i, &j| values[i].cmp(&values[j]));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        // Ranks start..end, counting from 1, averaged
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &i in &order[start..end] {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Spearman's rank correlation of the pairs `(a[i], b[i])`: the Pearson
+/// correlation of their ranks. `None` if either side is constant.
+pub fn spearman<T: Ord>(a: &[T], b: &[T]) -> Option<f64> {
+    let n = a.len().min(b.len());
+    let (x, y) = (ranks(&a[..n]), ranks(&b[..n]));
+    let mean = (n as f64 + 1.0) / 2.0;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for (x, y) in x.iter().zip(&y) {
+        covariance += (x - mean) * (y - mean);
+        variance_x += (x - mean) * (x - mean);
+        variance_y += (y - mean) * (y - mean);
+    }
+    let denominator = (variance_x * variance_y).sqrt();
+    (denominator > 0.0).then(|| covariance / denominator)
+}
+
+/// Size of the multiset intersection of `a` and `b` over the size of their
+/// union. `None` if both are empty.
+pub fn jaccard<T: Number>(a: &[T], b: &[T]) -> Option<f64> {
+    let mut counts: HashMap<&T, (usize, usize)> = HashMap::new();
+    for value in a {
+        counts.entry(value).or_default().0 += 1;
+    }
+    for value in b {
+        counts.entry(value).or_default().1 += 1;
+    }
+    let (intersection, union) = counts
+        .values()
+        .fold((0, 0), |(i, u), &(x, y)| (i + x.min(y), u + x.max(y)));
+    (union > 0).then(|| intersection as f64 / union as f64)
+}
+
+/// The least work, moving a share of the total weight by one unit costing one,
+/// to turn the distribution of values in `a` into that of `b`: the area
+/// between their cumulative distributions. `None` if either is empty.
+pub fn earth_movers_distance<T: Number>(a: &[T], b: &[T]) -> Option<f64> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort();
+    b.sort();
+
+    let (mut i, mut j) = (0, 0);
+    let mut distance = 0.0;
+    let mut previous: Option<f64> = None;
+    while i < a.len() || j < b.len() {
+        // The next value in either column, and how much of each lies below it
+        let next = match (a.get(i), b.get(j)) {
+            (Some(x), Some(y)) => x.min(y),
+            (Some(x), None) => x,
+            (None, Some(y)) => y,
+            (None, None) => unreachable!(),
+        }
+        .clone();
+        let value = float(&next);
+        if let Some(previous) = previous {
+            let cdf_a = i as f64 / a.len() as f64;
+            let cdf_b = j as f64 / b.len() as f64;
+            distance += (cdf_a - cdf_b).abs() * (value - previous);
+        }
+        while a.get(i) == Some(&next) {
+            i += 1;
+        }
+        while b.get(j) == Some(&next) {
+            j += 1;
+        }
+        previous = Some(value);
+    }
+    Some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ties_and_degenerate_columns_are_handled() {
+        // Perfectly ordered, with a tie on each side
+        assert_eq!(kendall_tau(&[1, 2, 2, 3], &[10, 20, 20, 30]), Some(1.0));
+        assert_eq!(spearman(&[1, 2, 2, 3], &[10, 20, 20, 30]), Some(1.0));
+        assert_eq!(spearman(&[3, 2, 1], &[1, 2, 3]), Some(-1.0));
+        // A constant column has no rank correlation
+        assert_eq!(kendall_tau(&[5, 5, 5], &[1, 2, 3]), None);
+        assert_eq!(spearman(&[5, 5, 5], &[1, 2, 3]), None);
+
+        assert_eq!(jaccard::<i64>(&[], &[]), None);
+        assert_eq!(jaccard(&[1, 1, 2], &[1, 3]), Some(0.25));
+
+        assert_eq!(earth_movers_distance::<i64>(&[], &[1]), None);
+        // Half the weight moves from 0 to 2
+        assert_eq!(earth_movers_distance(&[0, 2], &[2]), Some(1.0));
+    }
+}
@@ -0,0 +1,280 @@
+use aoc_core::{parse_field, Error, Result, Solution};
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+pub struct Day2;
+
+/// Which way a report's levels may change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+    /// Increasing or decreasing, but not both.
+    Either,
+    /// Up and down in any order.
+    Any,
+}
+
+impl FromStr for Trend {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s {
+            "increasing" => Ok(Trend::Increasing),
+            "decreasing" => Ok(Trend::Decreasing),
+            "either" => Ok(Trend::Either),
+            "any" => Ok(Trend::Any),
+            _ => Err(format!(
+                "unknown direction {:?}; expected increasing, decreasing, either or any",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Trend::Increasing => "increasing",
+            Trend::Decreasing => "decreasing",
+            Trend::Either => "either",
+            Trend::Any => "any",
+        })
+    }
+}
+
+/// What makes a report safe. The default is the puzzle's: at least two
+/// levels, all increasing or all decreasing, by 1 to 3 at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyRules {
+    /// Smallest allowed difference between adjacent levels that differ.
+    pub min_step: u32,
+    /// Largest allowed difference between adjacent levels.
+    pub max_step: u32,
+    pub direction: Trend,
+    /// Whether adjacent levels may be equal.
+    pub plateaus: bool,
+    /// Fewest levels a safe report has.
+    pub min_length: usize,
+}
+
+impl Default for SafetyRules {
+    fn default() -> Self {
+        SafetyRules {
+            min_step: 1,
+            max_step: 3,
+            direction: Trend::Either,
+            plateaus: false,
+            min_length: 2,
+        }
+    }
+}
+
+impl Display for SafetyRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "steps of {} to {}, {}, {}, at least {} levels",
+            self.min_step,
+            self.max_step,
+            match self.direction {
+                Trend::Either => "increasing or decreasing",
+                Trend::Any => "in any direction",
+                Trend::Increasing => "increasing",
+                Trend::Decreasing => "decreasing",
+            },
+            if self.plateaus {
+                "plateaus allowed"
+            } else {
+                "no plateaus"
+            },
+            self.min_length
+        )
+    }
+}
+
+impl SafetyRules {
+    /// Rejects rules no report could meet.
+    pub fn check(&self) -> Result<()> {
+        if self.min_step > self.max_step {
+            return Err(Error::puzzle(format!(
+                "the minimum step ({}) is larger than the maximum ({})",
+                self.min_step, self.max_step
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn is_safe(&self, numbers: &[i32]) -> bool {
+        if numbers.len() < self.min_length {
+            return false;
+        }
+
+        let mut increased = false;
+        let mut decreased = false;
+
+        for pair in numbers.windows(2) {
+            let (previous, current) = (pair[0], pair[1]);
+            match current.cmp(&previous) {
+                Ordering::Equal if !self.plateaus => return false,
+                Ordering::Equal => continue,
+                Ordering::Greater => increased = true,
+                Ordering::Less => decreased = true,
+            }
+            let step = current.abs_diff(previous);
+            if !(self.min_step..=self.max_step).contains(&step) {
+                return false; // Invalid difference
+            }
+        }
+
+        match self.direction {
+            Trend::Increasing => !decreased,
+            Trend::Decreasing => !increased,
+            Trend::Either => !(increased && decreased),
+            Trend::Any => true,
+        }
+    }
+
+    /// Whether the report is safe, or would be with one level removed.
+    pub fn is_fixable(&self, numbers: &[i32]) -> bool {
+        if self.is_safe(numbers) {
+            return true;
+        }
+        // Try removing each number one at a time
+        (0..numbers.len()).any(|skip_index| {
+            let modified_sequence: Vec<i32> = numbers
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != skip_index)
+                .map(|(_, &n)| n)
+                .collect();
+            self.is_safe(&modified_sequence)
+        })
+    }
+}
+
+/// Reports that are safe under `rules`.
+pub fn count_safe(reports: &[Vec<i32>], rules: &SafetyRules) -> usize {
+    reports
+        .iter()
+        .filter(|numbers| rules.is_safe(numbers))
+        .count()
+}
+
+/// Reports that are safe under `rules` once at most one level is removed.
+pub fn count_fixable(reports: &[Vec<i32>], rules: &SafetyRules) -> usize {
+    reports
+        .iter()
+        .filter(|numbers| rules.is_fixable(numbers))
+        .count()
+}
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.split_whitespace()
+                    .map(|s| parse_field(i, line, s))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_one(&self, reports: &Self::Input) -> Result<usize> {
+        Ok(count_safe(reports, &SafetyRules::default()))
+    }
+
+    fn part_two(&self, reports: &Self::Input) -> Result<usize> {
+        Ok(count_fixable(reports, &SafetyRules::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_rules_are_the_puzzles() {
+        let rules = SafetyRules::default();
+        assert!(rules.is_safe(&[7, 6, 4, 2, 1]));
+        assert!(!rules.is_safe(&[1, 2, 7, 8, 9]));
+        assert!(!rules.is_safe(&[8, 6, 4, 4, 1]));
+        assert!(!rules.is_safe(&[1, 3, 2, 4, 5]));
+        assert!(!rules.is_safe(&[5]));
+        assert!(rules.is_fixable(&[1, 3, 2, 4, 5]));
+        assert!(!rules.is_fixable(&[9, 7, 6, 2, 1]));
+    }
+
+    #[test]
+    fn rules_can_be_loosened_or_tightened() {
+        let plateaus = SafetyRules {
+            plateaus: true,
+            ..SafetyRules::default()
+        };
+        assert!(plateaus.is_safe(&[8, 6, 4, 4, 1]));
+        assert!(!plateaus.is_safe(&[1, 2, 2, 1]));
+        assert!(plateaus.is_safe(&[3, 3]));
+
+        let any = SafetyRules {
+            direction: Trend::Any,
+            ..SafetyRules::default()
+        };
+        assert!(any.is_safe(&[1, 3, 2, 4, 5]));
+        let increasing = SafetyRules {
+            direction: Trend::Increasing,
+            ..SafetyRules::default()
+        };
+        assert!(!increasing.is_safe(&[7, 6, 4, 2, 1]));
+        assert!(increasing.is_safe(&[1, 3, 6, 7, 9]));
+
+        let long_steps = SafetyRules {
+            min_step: 2,
+            max_step: 5,
+            min_length: 3,
+            ..SafetyRules::default()
+        };
+        assert!(long_steps.is_safe(&[1, 6, 8]));
+        assert!(!long_steps.is_safe(&[1, 6]));
+        assert!(!long_steps.is_safe(&[1, 2, 4]));
+        assert!(long_steps.is_fixable(&[1, 2, 4, 6]));
+
+        let reports = vec![vec![1, 2, 3], vec![3, 2, 1], vec![1, 1, 1], vec![1, 5, 2]];
+        assert_eq!(count_safe(&reports, &any), 2);
+        assert_eq!(count_fixable(&reports, &any), 3);
+
+        assert_eq!(
+            SafetyRules {
+                min_step: 4,
+                ..SafetyRules::default()
+            }
+            .check()
+            .unwrap_err()
+            .to_string(),
+            "the minimum step (4) is larger than the maximum (3)"
+        );
+    }
+
+    #[test]
+    fn safe_reports_are_fixable_at_the_minimum_length() {
+        let rules = SafetyRules {
+            min_length: 5,
+            ..SafetyRules::default()
+        };
+        // Removing a level would leave it too short, but it needn't lose one
+        assert!(rules.is_safe(&[1, 2, 3, 4, 5]));
+        assert!(rules.is_fixable(&[1, 2, 3, 4, 5]));
+        assert!(!rules.is_fixable(&[1, 2, 3, 4]));
+        assert!(rules.is_fixable(&[1, 2, 3, 9, 4, 5]));
+
+        let reports = vec![vec![1, 2, 3, 4, 5], vec![5, 4, 3, 2, 1, 1], vec![1, 2]];
+        assert_eq!(count_safe(&reports, &rules), 1);
+        assert_eq!(count_fixable(&reports, &rules), 2);
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_core::{Error, Result, Solution};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// mul(number,number)
+static MUL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"mul\((\d+),(\d+)\)").unwrap());
+/// Everything from the start or a do() up to the next don't() or the end.
+/// Sections may span lines.
+static ENABLED: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)(?:^|do\(\))(.*?)(?:don't\(\)|$)").unwrap());
+
+pub struct Day3;
+
+/// 1-based line and column of the byte at `offset` in `contents`.
+fn position(contents: &str, offset: usize) -> (usize, usize) {
+    let before = &contents[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Sum of the products of every mul(x,y) in `section`, which starts `offset`
+/// bytes into `contents`.
+fn sum_products(contents: &str, offset: usize, section: &str) -> Result<u64> {
+    let mut sum: u64 = 0;
+
+    for cap in MUL.captures_iter(section) {
+        let [num1, num2] = [1, 2].map(|i| {
+            let field = cap.get(i).unwrap();
+            field.as_str().parse::<u64>().map_err(|e| {
+                let (line, column) = position(contents, offset + field.start());
+                Error::parse(
+                    line,
+                    column,
+                    format!("invalid value {:?}: {}", field.as_str(), e),
+                )
+            })
+        });
+        sum = num1?
+            .checked_mul(num2?)
+            .and_then(|product| sum.checked_add(product))
+            .ok_or_else(|| Error::puzzle("sum of products overflows u64"))?;
+    }
+
+    Ok(sum)
+}
+
+impl Solution for Day3 {
+    type Input = String;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(&self, contents: &Self::Input) -> Result<u64> {
+        sum_products(contents, 0, contents)
+    }
+
+    fn part_two(&self, contents: &Self::Input) -> Result<u64> {
+        let mut sum: u64 = 0;
+
+        // For each section between do() and don't()
+        for section in ENABLED.captures_iter(contents) {
+            let section = section.get(1).unwrap();
+            sum = sum
+                .checked_add(sum_products(contents, section.start(), section.as_str())?)
+                .ok_or_else(|| Error::puzzle("sum of products overflows u64"))?;
+        }
+
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_numbers_are_reported_where_they_are() {
+        let input = "xmul(2,3)\ndo()mul(99999999999999999999,2)".to_string();
+        match Day3.part_two(&input) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 9)),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let input = "mul(4294967296,4294967296)".to_string();
+        assert_eq!(
+            Day3.part_one(&input).unwrap_err().to_string(),
+            "sum of products overflows u64"
+        );
+    }
+}
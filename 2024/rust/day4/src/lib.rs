@@ -0,0 +1,72 @@
+use aoc_core::{Direction, Grid, Point, Result, Solution};
+
+fn search_word(grid: &Grid<char>, word: &str, start: Point, dir: Direction) -> bool {
+    // The ray stops at the edge, so a word that doesn't fit comes up short
+    grid.ray(start, dir.delta())
+        .take(word.len())
+        .copied()
+        .eq(word.chars())
+}
+
+fn find_word(grid: &Grid<char>, word: &str) -> Vec<(Point, &'static str)> {
+    let mut found_positions = Vec::new();
+
+    for (pos, _) in grid.iter() {
+        for dir in Direction::ALL {
+            if search_word(grid, word, pos, dir) {
+                found_positions.push((pos, dir.name()));
+            }
+        }
+    }
+    found_positions
+}
+
+fn check_x_pattern(grid: &Grid<char>, top_left: Point, word: &str) -> bool {
+    let word_len = word.len();
+
+    // Diagonal from top-left to bottom-right
+    let diagonal1: Vec<char> = grid.diagonal(top_left).take(word_len).copied().collect();
+    // Diagonal from top-right to bottom-left
+    let diagonal2: Vec<char> = grid
+        .anti_diagonal(top_left + Direction::Right.delta() * (word_len as isize - 1))
+        .take(word_len)
+        .copied()
+        .collect();
+
+    // Check if either diagonal spells the word
+    let target: Vec<char> = word.chars().collect();
+    let target_rev: Vec<char> = word.chars().rev().collect();
+
+    // Each diagonal may spell the word forward or reversed, independently
+    (diagonal1 == target || diagonal1 == target_rev)
+        && (diagonal2 == target || diagonal2 == target_rev)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Grid::parse(input)
+    }
+
+    fn part_one(&self, grid: &Self::Input) -> Result<usize> {
+        let word = "XMAS"; // Replace with your target word
+        Ok(find_word(grid, word).len())
+    }
+
+    fn part_two(&self, grid: &Self::Input) -> Result<usize> {
+        let word = "MAS"; // Can be changed to any word
+
+        // Check every grid position as the top-left corner of an X
+        let found = grid
+            .iter()
+            .filter(|&(pos, _)| check_x_pattern(grid, pos, word))
+            .count();
+
+        Ok(found)
+    }
+}
@@ -0,0 +1,216 @@
+use aoc_core::{parse_field, Error, Result, Solution};
+use std::collections::{HashMap, VecDeque};
+use tracing::debug;
+
+fn get_middle_index(vec: &[i32]) -> usize {
+    vec.len() / 2 // For even lengths this gives the right middle index
+}
+
+/// Every ordering rule as an edge from the page that comes first, sorted.
+pub fn rules(map: &HashMap<i32, HashMap<String, Vec<i32>>>) -> Vec<(i32, i32)> {
+    let mut edges: Vec<(i32, i32)> = map
+        .iter()
+        .flat_map(|(&node, relationships)| {
+            relationships
+                .get("after")
+                .into_iter()
+                .flatten()
+                .map(move |&after_node| (node, after_node))
+        })
+        .collect();
+    edges.sort_unstable();
+    edges
+}
+
+fn check_sequence(numbers: &[i32], map: &HashMap<i32, HashMap<String, Vec<i32>>>) -> bool {
+    let mut correct = true;
+    // For each number in the sequence
+    for (i, &current_num) in numbers.iter().enumerate() {
+        // Get all numbers after current_num in the sequence
+        let numbers_before = &numbers[..i];
+        let numbers_after = &numbers[i + 1..];
+        // Check if current number exists in map
+        if let Some(relationships) = map.get(&current_num) {
+            // Get "before" relationships from map
+            if let Some(should_be_before) = relationships.get("before") {
+                // Check that none of the numbers that should be before are actually after
+                for &num in numbers_after {
+                    if should_be_before.contains(&num) {
+                        correct = false;
+                    }
+                }
+            }
+
+            // Get "after" relationships from map
+            if let Some(should_be_after) = relationships.get("after") {
+                // Check that all numbers that should be after are actually after
+                for &num in numbers_before {
+                    if should_be_after.contains(&num) {
+                        correct = false;
+                    }
+                }
+            }
+        }
+    }
+    correct
+}
+
+fn topological_sort(
+    numbers: &[i32],
+    map: &HashMap<i32, HashMap<String, Vec<i32>>>,
+) -> Result<Vec<i32>> {
+    let mut in_degree = HashMap::new();
+    let mut graph = HashMap::new();
+
+    // Initialize in-degree and graph
+    for &num in numbers {
+        in_degree.insert(num, 0);
+        graph.insert(num, Vec::new());
+    }
+
+    // Build the graph and compute in-degrees
+    for &num in numbers {
+        if let Some(relationships) = map.get(&num) {
+            if let Some(should_be_after) = relationships.get("after") {
+                for &after_num in should_be_after {
+                    if in_degree.contains_key(&after_num) {
+                        graph.get_mut(&num).unwrap().push(after_num);
+                        *in_degree.get_mut(&after_num).unwrap() += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    // Collect nodes with zero in-degree
+    let mut queue = VecDeque::new();
+    for (&num, &degree) in &in_degree {
+        if degree == 0 {
+            queue.push_back(num);
+        }
+    }
+
+    // Perform topological sort
+    let mut sorted = Vec::new();
+    while let Some(num) = queue.pop_front() {
+        sorted.push(num);
+        if let Some(neighbors) = graph.get(&num) {
+            for &neighbor in neighbors {
+                let degree = in_degree.get_mut(&neighbor).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+
+    // If sorted length is not equal to numbers length, there was a cycle
+    if sorted.len() != numbers.len() {
+        return Err(Error::puzzle(format!(
+            "cycle detected in the ordering rules for {:?}, cannot sort",
+            numbers
+        )));
+    }
+
+    Ok(sorted)
+}
+
+pub struct Day5;
+
+/// Ordering rules keyed by page, plus the updates to check against them.
+pub struct PrintQueue {
+    pub map: HashMap<i32, HashMap<String, Vec<i32>>>,
+    pub updates: Vec<Vec<i32>>,
+}
+
+impl Solution for Day5 {
+    type Input = PrintQueue;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input> {
+        let mut map: HashMap<i32, HashMap<String, Vec<i32>>> = HashMap::new();
+        let mut lines = contents.lines().enumerate();
+
+        // Process the lines up to the first empty line (which is consumed too)
+        for (i, line) in lines.by_ref().take_while(|(_, line)| !line.is_empty()) {
+            let Some((num1, num2)) = line.split_once('|') else {
+                return Err(Error::parse(i + 1, 1, "expected a rule of the form X|Y"));
+            };
+
+            let num1: i32 = parse_field(i, line, num1.trim())?;
+            let num2: i32 = parse_field(i, line, num2.trim())?;
+
+            // Handle num1 -> num2 relationship (after)
+            if let Some(inner_map) = map.get_mut(&num1) {
+                inner_map
+                    .entry("after".to_string())
+                    .or_insert_with(Vec::new)
+                    .push(num2);
+            } else {
+                let mut inner_map = HashMap::new();
+                inner_map.insert("after".to_string(), vec![num2]);
+                map.insert(num1, inner_map);
+            }
+
+            // Handle num2 -> num1 relationship (before)
+            if let Some(inner_map) = map.get_mut(&num2) {
+                inner_map
+                    .entry("before".to_string())
+                    .or_insert_with(Vec::new)
+                    .push(num1);
+            } else {
+                let mut inner_map = HashMap::new();
+                inner_map.insert("before".to_string(), vec![num1]);
+                map.insert(num2, inner_map);
+            }
+        }
+
+        // Read the second section (after empty line)
+        let updates = lines
+            .filter(|(_, line)| !line.is_empty()) // Skip any additional empty lines
+            .map(|(i, line)| {
+                line.split(',')
+                    .map(|n| parse_field(i, line, n.trim()))
+                    .collect()
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(PrintQueue { map, updates })
+    }
+
+    fn part_one(&self, queue: &Self::Input) -> Result<i32> {
+        let mut sum: i32 = 0;
+        for numbers in &queue.updates {
+            if check_sequence(numbers, &queue.map) {
+                let middle_index = get_middle_index(numbers);
+                // Add the middle number to sum
+                sum = sum
+                    .checked_add(numbers[middle_index])
+                    .ok_or_else(|| Error::puzzle("sum of middle pages overflows i32"))?;
+            }
+        }
+        Ok(sum)
+    }
+
+    fn part_two(&self, queue: &Self::Input) -> Result<i32> {
+        let mut sum: i32 = 0;
+        for numbers in &queue.updates {
+            // Fix the sequence using topological sort
+            let fixed_numbers = topological_sort(numbers, &queue.map)?;
+
+            if fixed_numbers == *numbers {
+                debug!(update = ?numbers, "sequence is correct");
+            } else {
+                debug!(original = ?numbers, fixed = ?fixed_numbers, "fixed sequence");
+                let middle_index = get_middle_index(&fixed_numbers);
+                // Add the middle number to sum
+                sum = sum
+                    .checked_add(fixed_numbers[middle_index])
+                    .ok_or_else(|| Error::puzzle("sum of middle pages overflows i32"))?;
+            }
+        }
+        Ok(sum)
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_core::{Direction, Error, Grid, Point, Result, Solution};
+use std::collections::HashSet;
+
+fn is_loop(grid: &Grid<char>, start_pos: Point) -> bool {
+    let mut pos_with_dir = HashSet::new();
+    let (mut curr_pos, mut curr_dir) = (start_pos, Direction::Up);
+
+    pos_with_dir.insert((curr_pos, curr_dir));
+
+    loop {
+        let next_pos = curr_pos + curr_dir;
+
+        match grid.get(next_pos) {
+            // Walked off the map
+            None => return false,
+            // Obstacle ahead
+            Some('#') => curr_dir = curr_dir.turn_right(),
+            Some(_) => curr_pos = next_pos,
+        }
+
+        // If we've seen this position and direction before, it's a loop
+        if !pos_with_dir.insert((curr_pos, curr_dir)) {
+            return true;
+        }
+    }
+}
+
+fn find_start(grid: &Grid<char>) -> Result<Point> {
+    grid.find(&'^')
+        .ok_or_else(|| Error::puzzle("no guard ('^') found on the map"))
+}
+
+/// Every position the guard stands on, in order, until they walk off the map.
+pub fn guard_path(grid: &Grid<char>) -> Result<Vec<Point>> {
+    let (mut curr_pos, mut curr_dir) = (find_start(grid)?, Direction::Up);
+    let mut path = vec![curr_pos];
+
+    loop {
+        let next_pos = curr_pos + curr_dir;
+
+        match grid.get(next_pos) {
+            // Walked off the map
+            None => break,
+            // Obstacle ahead
+            Some('#') => curr_dir = curr_dir.turn_right(),
+            Some(_) => {
+                curr_pos = next_pos;
+                path.push(curr_pos);
+            }
+        }
+    }
+
+    Ok(path)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Grid::parse(input)
+    }
+
+    fn part_one(&self, grid: &Self::Input) -> Result<usize> {
+        let visited: HashSet<Point> = guard_path(grid)?.into_iter().collect();
+        Ok(visited.len())
+    }
+
+    fn part_two(&self, grid: &Self::Input) -> Result<usize> {
+        part_two_naive(grid)
+    }
+}
+
+fn part_two_naive(grid: &Grid<char>) -> Result<usize> {
+    let mut grid = grid.clone();
+    let start_pos = find_start(&grid)?;
+    let candidates: Vec<Point> = grid
+        .iter()
+        .filter(|&(pos, &cell)| cell == '.' && pos != start_pos)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    // Try placing an obstruction at each empty position
+    let mut loop_positions = 0;
+    for pos in candidates {
+        grid.set(pos, '#');
+        if is_loop(&grid, start_pos) {
+            loop_positions += 1;
+        }
+        // Remove the obstruction
+        grid.set(pos, '.');
+    }
+
+    Ok(loop_positions)
+}
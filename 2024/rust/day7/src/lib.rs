@@ -0,0 +1,145 @@
+use aoc_core::{parse_field, Error, Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Multiply,
+    /// Joins the digits of both sides: 12 || 345 is 12345.
+    Concatenate,
+}
+
+use Operator::*;
+
+fn concatenate(left: i64, right: i64) -> Option<i64> {
+    if right < 0 {
+        return None;
+    }
+    // The smallest power of ten above `right`, to shift `left` past its digits
+    let mut shift: i64 = 10;
+    while shift <= right {
+        shift = shift.checked_mul(10)?;
+    }
+    let shifted = left.checked_mul(shift)?;
+    if left < 0 {
+        shifted.checked_sub(right)
+    } else {
+        shifted.checked_add(right)
+    }
+}
+
+impl Operator {
+    /// `left op right`, or `None` if that overflows.
+    fn apply(self, left: i64, right: i64) -> Option<i64> {
+        match self {
+            Add => left.checked_add(right),
+            Multiply => left.checked_mul(right),
+            Concatenate => concatenate(left, right),
+        }
+    }
+}
+
+/// Whether some choice of `operators` between `nums` gives `target`.
+fn can_make_value(target: i64, nums: &[i64], operators: &[Operator]) -> bool {
+    // With only positive numbers no operator makes the total smaller, so a
+    // total past the target can be abandoned
+    let growing = nums.iter().all(|&n| n > 0);
+    search(target, nums[0], &nums[1..], operators, growing)
+}
+
+/// Depth-first search over the operators between `total` and `rest`, applied
+/// left to right.
+fn search(target: i64, total: i64, rest: &[i64], operators: &[Operator], growing: bool) -> bool {
+    let Some((&next, rest)) = rest.split_first() else {
+        return total == target;
+    };
+    if growing && total > target {
+        return false;
+    }
+    operators.iter().any(|op| {
+        // A combination that overflows is larger than any target
+        op.apply(total, next)
+            .is_some_and(|total| search(target, total, rest, operators, growing))
+    })
+}
+
+/// Sum of the targets some choice of `operators` can make.
+fn calibration_total(equations: &[(i64, Vec<i64>)], operators: &[Operator]) -> Result<i64> {
+    equations
+        .iter()
+        .filter(|(target, nums)| can_make_value(*target, nums, operators))
+        .try_fold(0i64, |sum, (target, _)| {
+            sum.checked_add(*target)
+                .ok_or_else(|| Error::puzzle("calibration total overflows i64"))
+        })
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let Some((target, nums)) = line.split_once(':') else {
+                    return Err(Error::parse(i + 1, 1, "expected 'target: numbers...'"));
+                };
+                let target: i64 = parse_field(i, line, target.trim())?;
+                let nums: Vec<i64> = nums
+                    .split_whitespace()
+                    .map(|n| parse_field(i, line, n))
+                    .collect::<Result<_>>()?;
+                if nums.is_empty() {
+                    return Err(Error::parse(
+                        i + 1,
+                        line.len() + 1,
+                        "expected at least one number after ':'",
+                    ));
+                }
+                Ok((target, nums))
+            })
+            .collect()
+    }
+
+    fn part_one(&self, equations: &Self::Input) -> Result<i64> {
+        calibration_total(equations, &[Add, Multiply])
+    }
+
+    fn part_two(&self, equations: &Self::Input) -> Result<i64> {
+        calibration_total(equations, &[Add, Multiply, Concatenate])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concatenation_is_checked() {
+        assert_eq!(concatenate(12, 345), Some(12345));
+        assert_eq!(concatenate(7, 0), Some(70));
+        assert_eq!(concatenate(i64::MAX / 10, 99), None);
+        // Overflowing combinations just don't match
+        assert!(!can_make_value(
+            1,
+            &[i64::MAX, 9],
+            &[Add, Multiply, Concatenate]
+        ));
+        assert!(can_make_value(156, &[15, 6], &[Add, Multiply, Concatenate]));
+    }
+
+    #[test]
+    fn long_equations_are_searched_without_overflow() {
+        let operators = [Add, Multiply, Concatenate];
+        let twos = [2; 42];
+        assert!(!can_make_value(1, &twos, &operators));
+        assert!(can_make_value(84, &twos, &operators));
+        let ones = [1; 42];
+        assert!(can_make_value(1, &ones, &operators));
+        assert!(!can_make_value(-1, &ones, &operators));
+    }
+}
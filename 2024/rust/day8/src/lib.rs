@@ -0,0 +1,116 @@
+use aoc_core::{Grid, Point, Result, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use tracing::{debug, enabled, Level};
+
+pub struct Day8;
+
+/// The map, plus antenna positions grouped by frequency.
+pub struct Antennas {
+    pub grid: Grid<char>,
+    pub positions: HashMap<char, Vec<Point>>,
+}
+
+fn log_found_points(grid: &Grid<char>, found_points: &HashSet<Point>) {
+    // Drawing the map is as much work as the puzzle, so only do it when asked
+    if !enabled!(Level::DEBUG) {
+        return;
+    }
+    let mut map = grid.map(|_| '.');
+    for &point in found_points {
+        map.set(point, '#');
+    }
+    debug!("antinodes:\n{}", map);
+}
+
+/// Points in line with two antennas of the same frequency, twice as far from
+/// one as from the other.
+pub fn antinodes(antennas: &Antennas) -> HashSet<Point> {
+    let grid = &antennas.grid;
+    let mut found_points: HashSet<Point> = HashSet::new();
+    for positions in antennas.positions.values() {
+        let n = positions.len();
+        if n >= 2 {
+            for i in 0..n - 1 {
+                for j in i + 1..n {
+                    let (p1, p2) = (positions[i], positions[j]);
+
+                    // delta is the direction of the line from p1 to p2: step back from
+                    // p1 and forward from p2 to land on the antinodes
+                    let delta = p2 - p1;
+                    for antinode in [p1 - delta, p2 + delta] {
+                        if grid.in_bounds(antinode) {
+                            found_points.insert(antinode);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    found_points
+}
+
+/// Points in line with two antennas of the same frequency, at any distance.
+pub fn resonant_antinodes(antennas: &Antennas) -> HashSet<Point> {
+    let grid = &antennas.grid;
+    let mut found_points: HashSet<Point> = HashSet::new();
+    for positions in antennas.positions.values() {
+        let n = positions.len();
+        if n >= 2 {
+            for i in 0..n - 1 {
+                for j in i + 1..n {
+                    let (p1, p2) = (positions[i], positions[j]);
+
+                    found_points.insert(p1);
+                    found_points.insert(p2);
+                    // delta is the direction of the line from p1 to p2: keep stepping
+                    // back from p1 and forward from p2 until we leave the map
+                    let delta = p2 - p1;
+                    let mut antinode = p1 - delta;
+                    while grid.in_bounds(antinode) {
+                        found_points.insert(antinode);
+                        antinode -= delta;
+                    }
+                    let mut antinode = p2 + delta;
+                    while grid.in_bounds(antinode) {
+                        found_points.insert(antinode);
+                        antinode += delta;
+                    }
+                }
+            }
+        }
+    }
+    found_points
+}
+
+impl Solution for Day8 {
+    type Input = Antennas;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input)?;
+        let mut positions: HashMap<char, Vec<Point>> = HashMap::new();
+        for (pos, &c) in grid.iter() {
+            if c != '.' {
+                positions.entry(c).or_default().push(pos);
+            }
+        }
+        for (c, pos) in positions.iter() {
+            debug!(frequency = %c, positions = ?pos, "antennas");
+        }
+        Ok(Antennas { grid, positions })
+    }
+
+    fn part_one(&self, antennas: &Self::Input) -> Result<usize> {
+        let found_points = antinodes(antennas);
+        log_found_points(&antennas.grid, &found_points);
+        Ok(found_points.len())
+    }
+
+    fn part_two(&self, antennas: &Self::Input) -> Result<usize> {
+        let found_points = resonant_antinodes(antennas);
+        log_found_points(&antennas.grid, &found_points);
+        Ok(found_points.len())
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Everything that can go wrong between reading a puzzle input and producing
+/// an answer.
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The input is malformed. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input parsed, but the puzzle has no answer for it.
+    Puzzle(String),
+    /// The part hasn't been written yet, as in a freshly scaffolded day. The
+    /// runner skips it instead of counting it as a failure.
+    Unsolved,
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn puzzle(message: impl Into<String>) -> Self {
+        Error::Puzzle(message.into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "cannot read {}: {}", path.display(), source),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            Error::Puzzle(message) => write!(f, "{}", message),
+            Error::Unsolved => f.write_str("not solved yet"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Parses `field`, which must be a slice of `line`, reporting the field's
+/// position on failure. `line_index` is 0-based, as produced by `enumerate()`.
+pub fn parse_field<T>(line_index: usize, line: &str, field: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field.parse().map_err(|e| {
+        Error::parse(
+            line_index + 1,
+            column_of(line, field),
+            format!("invalid value {:?}: {}", field, e),
+        )
+    })
+}
+
+/// 1-based column at which `field` starts within `line`. Falls back to 1 if
+/// `field` isn't actually a slice of `line`.
+pub fn column_of(line: &str, field: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (field.as_ptr() as usize).wrapping_sub(start);
+    if offset <= line.len() {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
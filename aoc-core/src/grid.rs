@@ -0,0 +1,317 @@
+use crate::{Direction, Error, Point, Result};
+use std::fmt::{self, Display};
+
+/// A rectangular grid stored row-major in one `Vec`.
+///
+/// Cells are addressed by signed [`Point`]s, `x` being the column and `y` the
+/// row counted from the top, so stepping off an edge is just a `None` from
+/// [`Grid::get`] rather than an underflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parses one row per line. All lines must have the same length.
+    pub fn parse(input: &str) -> Result<Self> {
+        Grid::parse_with(input, Ok)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per line, converting each character with `cell`.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (i, line) in input.lines().enumerate() {
+            let len = line.chars().count();
+            let width = *width.get_or_insert(len);
+            if len != width {
+                return Err(Error::parse(
+                    i + 1,
+                    len.min(width) + 1,
+                    format!("expected {} characters per line, found {}", width, len),
+                ));
+            }
+            for c in line.chars() {
+                cells.push(cell(c)?);
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(Error::puzzle("input is empty")),
+        }
+    }
+
+    /// A `width` by `height` grid of `fill`. A zero width or height makes an
+    /// empty grid.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, p: Point) -> Option<usize> {
+        if self.in_bounds(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Overwrites a cell, returning `false` if `p` is out of bounds.
+    pub fn set(&mut self, p: Point, value: T) -> bool {
+        match self.get_mut(p) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| {
+            let (x, y) = (i % self.width, i / self.width);
+            (Point::new(x as isize, y as isize), cell)
+        })
+    }
+
+    /// Position of the first cell (in row order) equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Not `chunks`, which panics on a zero width
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        // A zero-width grid has no cells, so any step will do
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells from `start` onwards in steps of `step`, until the edge.
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = &T> {
+        (0..)
+            .map(move |i| self.get(start + step * i))
+            .take_while(Option::is_some)
+            .flatten()
+    }
+
+    /// Cells from `start` going down and to the right.
+    pub fn diagonal(&self, start: Point) -> impl Iterator<Item = &T> {
+        self.ray(start, Direction::DownRight.delta())
+    }
+
+    /// Cells from `start` going down and to the left.
+    pub fn anti_diagonal(&self, start: Point) -> impl Iterator<Item = &T> {
+        self.ray(start, Direction::DownLeft.delta())
+    }
+
+    /// In-bounds orthogonal neighbours of `p`.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(p, &Direction::CARDINAL)
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `p`.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(p, &Direction::ALL)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        p: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .map(move |&direction| p + direction)
+            .filter(|&n| self.in_bounds(n))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+/// Renders the grid back to text, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "ab.\n.#c\n";
+
+    #[test]
+    fn parses_rows_and_rejects_ragged_ones() {
+        let grid = Grid::parse(SAMPLE).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), "ab.\n.#c");
+
+        match Grid::parse("abc\nab\n") {
+            Err(Error::Parse {
+                line,
+                column,
+                message,
+            }) => {
+                assert_eq!((line, column), (2, 3));
+                assert_eq!(message, "expected 3 characters per line, found 2");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(matches!(Grid::parse(""), Err(Error::Puzzle(_))));
+        assert!(matches!(Grid::parse("\n\n"), Err(Error::Puzzle(_))));
+
+        let digit = |c: char| c.to_digit(10).ok_or_else(|| Error::puzzle("not a digit"));
+        let digits = Grid::parse_with("12\n34", digit).unwrap();
+        assert_eq!(digits.get(Point::new(1, 1)), Some(&4));
+        assert!(Grid::parse_with("1x", digit).is_err());
+    }
+
+    #[test]
+    fn cells_outside_the_edges_are_none() {
+        let mut grid = Grid::parse(SAMPLE).unwrap();
+        assert_eq!(grid.get(Point::new(0, 0)), Some(&'a'));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'c'));
+        for p in [
+            Point::new(-1, 0),
+            Point::new(0, -1),
+            Point::new(3, 0),
+            Point::new(0, 2),
+        ] {
+            assert!(!grid.in_bounds(p));
+            assert_eq!(grid.get(p), None);
+        }
+        assert!(grid.set(Point::new(2, 0), 'x'));
+        assert!(!grid.set(Point::new(3, 0), 'x'));
+        assert_eq!(grid.row(0), ['a', 'b', 'x']);
+    }
+
+    #[test]
+    fn rays_and_diagonals_stop_at_the_edge() {
+        let grid = Grid::parse("abc\ndef\nghi").unwrap();
+        let collect = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+        assert_eq!(
+            collect(&mut grid.ray(Point::new(0, 1), Direction::Right.delta())),
+            "def"
+        );
+        assert_eq!(
+            collect(&mut grid.ray(Point::new(2, 2), Direction::Up.delta())),
+            "ifc"
+        );
+        assert_eq!(
+            collect(&mut grid.ray(Point::new(0, 0), Direction::Right.delta() * 2)),
+            "ac"
+        );
+        assert_eq!(collect(&mut grid.diagonal(Point::new(0, 0))), "aei");
+        assert_eq!(collect(&mut grid.diagonal(Point::new(1, 0))), "bf");
+        assert_eq!(collect(&mut grid.anti_diagonal(Point::new(2, 0))), "ceg");
+        assert_eq!(
+            collect(&mut grid.ray(Point::new(5, 5), Point::new(1, 0))),
+            ""
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["adg", "beh", "cfi"]
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+        let corner: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(2, 1)).count(), 5);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn find_returns_the_first_match_in_row_order() {
+        let grid = Grid::parse("..^\n^..").unwrap();
+        assert_eq!(grid.find(&'^'), Some(Point::new(2, 0)));
+        assert_eq!(grid.find(&'#'), None);
+        assert_eq!(grid.map(|&c| c == '^').find(&true), Some(Point::new(2, 0)));
+    }
+
+    #[test]
+    fn zero_width_grids_have_empty_rows_and_no_columns() {
+        let grid = Grid::new(0, 2, '.');
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[]; 2]);
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.get(Point::ORIGIN), None);
+        assert_eq!(grid.to_string(), "\n");
+    }
+}
@@ -0,0 +1,11 @@
+//! Shared building blocks for the individual puzzle days.
+
+mod error;
+mod geometry;
+mod grid;
+mod solution;
+
+pub use error::{column_of, parse_field, Error, Result};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use solution::{Answer, DynSolution, Solution};
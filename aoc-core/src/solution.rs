@@ -0,0 +1,70 @@
+use crate::Result;
+use std::any::Any;
+use std::fmt::{self, Display};
+
+/// A puzzle day: parse the input once, then solve both parts from it.
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part_one(&self, input: &Self::Input) -> Result<Self::Output1>;
+    fn part_two(&self, input: &Self::Input) -> Result<Self::Output2>;
+}
+
+/// A solved part, rendered to its textual form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn new(value: impl Display) -> Self {
+        Answer(value.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Object-safe view of a [`Solution`] so the runner can hold every day in one
+/// table regardless of its input and output types. Parsed inputs can be shared
+/// between threads so both parts can run at once.
+pub trait DynSolution: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>>;
+    fn part_one(&self, input: &dyn Any) -> Result<Answer>;
+    fn part_two(&self, input: &dyn Any) -> Result<Answer>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part_one(&self, input: &dyn Any) -> Result<Answer> {
+        Solution::part_one(self, downcast::<S>(input)).map(Answer::new)
+    }
+
+    fn part_two(&self, input: &dyn Any) -> Result<Answer> {
+        Solution::part_two(self, downcast::<S>(input)).map(Answer::new)
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("input was parsed by a different solution")
+}
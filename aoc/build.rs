@@ -0,0 +1,69 @@
+//! Generates one test per sample sidecar (`<year>/rust/day<N>/<sample>.answers`),
+//! included by `tests/samples.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().unwrap().to_string_lossy().into_owned()
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut tests = String::new();
+
+    for year_dir in subdirs(&root) {
+        let Ok(year) = file_name(&year_dir).parse::<u16>() else {
+            continue;
+        };
+        let rust_dir = year_dir.join("rust");
+        println!("cargo:rerun-if-changed={}", rust_dir.display());
+
+        for day_dir in subdirs(&rust_dir) {
+            let Some(Ok(day)) = file_name(&day_dir)
+                .strip_prefix("day")
+                .map(str::parse::<u8>)
+            else {
+                continue;
+            };
+            println!("cargo:rerun-if-changed={}", day_dir.display());
+
+            let mut samples: Vec<String> = fs::read_dir(&day_dir)
+                .unwrap()
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "answers"))
+                .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+                .collect();
+            samples.sort();
+
+            for sample in samples {
+                let ident: String = sample
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .collect();
+                writeln!(
+                    tests,
+                    "#[test]\nfn y{year}_day{day}_{ident}() {{\n    aoc::samples::check({year}, {day}, {sample:?});\n}}\n",
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("sample_tests.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,172 @@
+//! Known-correct answers, one TOML file per year under `answers/`:
+//!
+//! ```toml
+//! [day5.input]
+//! part1 = "6949"
+//! part2 = "4145"
+//! ```
+//!
+//! Tables are keyed by day and then by input file name (relative to the day's
+//! directory), so several inputs per day can be checked. Answers the site
+//! rejected on submission are kept too, so they aren't submitted again:
+//!
+//! ```toml
+//! [[day7.input.rejected2]]
+//! answer = "354060705047465"
+//! hint = "too-high"
+//! ```
+
+use crate::registry::workspace_root;
+use crate::runner::Part;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected1: Vec<Rejected>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected2: Vec<Rejected>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// An answer the site said was wrong.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejected {
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+impl Rejected {
+    /// Whether this rejection proves `answer` wrong too: it's the same answer,
+    /// or a number on the wrong side of a too-high or too-low one.
+    pub fn rules_out(&self, answer: &str) -> bool {
+        if self.answer == answer {
+            return true;
+        }
+        let (Ok(answer), Ok(rejected)) = (answer.parse::<i128>(), self.answer.parse::<i128>())
+        else {
+            return false;
+        };
+        match self.hint {
+            Some(Hint::TooHigh) => answer >= rejected,
+            Some(Hint::TooLow) => answer <= rejected,
+            None => false,
+        }
+    }
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    pub fn rejected(&self, part: Part) -> &[Rejected] {
+        match part {
+            Part::One => &self.rejected1,
+            Part::Two => &self.rejected2,
+        }
+    }
+
+    /// The earlier rejection that rules `answer` out, if any.
+    pub fn ruled_out_by(&self, part: Part, answer: &str) -> Option<&Rejected> {
+        self.rejected(part).iter().find(|r| r.rules_out(answer))
+    }
+
+    pub fn reject(&mut self, part: Part, rejected: Rejected) {
+        match part {
+            Part::One => self.rejected1.push(rejected),
+            Part::Two => self.rejected2.push(rejected),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    pub year: u16,
+    days: BTreeMap<String, BTreeMap<String, Expected>>,
+}
+
+impl AnswerStore {
+    pub fn path(year: u16) -> PathBuf {
+        workspace_root()
+            .join("answers")
+            .join(format!("{}.toml", year))
+    }
+
+    /// Loads the store for `year`; a missing file is an empty store.
+    pub fn load(year: u16) -> io::Result<Self> {
+        let days = match std::fs::read_to_string(Self::path(year)) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(AnswerStore { year, days })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path(self.year);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let contents = toml::to_string(&self.days)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, contents)
+    }
+
+    pub fn expected(&self, day: u8, input: &str) -> Option<&Expected> {
+        self.days.get(&day_key(day))?.get(input)
+    }
+
+    /// Input files with recorded answers for `day`.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = &str> {
+        self.days
+            .get(&day_key(day))
+            .into_iter()
+            .flat_map(|inputs| inputs.keys().map(String::as_str))
+    }
+
+    fn entry(&mut self, day: u8, input: &str) -> &mut Expected {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_string())
+            .or_default()
+    }
+
+    pub fn record(&mut self, day: u8, input: &str, part: Part, answer: String) {
+        self.entry(day, input).set(part, answer);
+    }
+
+    pub fn reject(&mut self, day: u8, input: &str, part: Part, rejected: Rejected) {
+        self.entry(day, input).reject(part, rejected);
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{}", day)
+}
@@ -0,0 +1,211 @@
+//! Repeated, warmed-up timing of each day's parse and parts, with a JSON
+//! report that later runs can be compared against.
+
+use crate::input::Source;
+use crate::outln;
+use crate::registry::{workspace_root, Day};
+use crate::runner::{self, Part};
+use aoc_core::Result;
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Summarises at least one sample. The median of an even number of
+    /// samples is the mean of the middle two.
+    pub fn from_samples(mut samples: Vec<u64>) -> Self {
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+        let median_ns = if samples.len().is_multiple_of(2) {
+            samples[middle - 1].midpoint(samples[middle])
+        } else {
+            samples[middle]
+        };
+        Stats {
+            min_ns: samples[0],
+            median_ns,
+            max_ns: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl DayBench {
+    pub fn stats(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part one", self.part_one),
+            ("part two", self.part_two),
+        ]
+    }
+}
+
+/// One stage of a [`DayBench`], for `--format json` or `csv`.
+#[derive(Debug, Clone, Serialize)]
+pub struct StageRecord {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub stage: &'static str,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl DayBench {
+    pub fn records(&self) -> impl Iterator<Item = StageRecord> + '_ {
+        self.stats().into_iter().map(|(stage, stats)| StageRecord {
+            year: self.year,
+            day: self.day,
+            input: self.input.clone(),
+            stage,
+            min_ns: stats.min_ns,
+            median_ns: stats.median_ns,
+            max_ns: stats.max_ns,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: usize,
+    pub warmup: usize,
+    pub days: Vec<DayBench>,
+}
+
+impl Report {
+    /// Where `aoc bench` writes the report unless told otherwise.
+    pub fn default_path() -> PathBuf {
+        workspace_root().join("target/bench/latest.json")
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(io::Error::from)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Runs `f` `warmup` times untimed, then `runs` times timed.
+fn measure<T>(runs: usize, warmup: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..warmup {
+        black_box(f()?);
+    }
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = f()?;
+        samples.push(start.elapsed().as_nanos() as u64);
+        black_box(result);
+    }
+    Ok(Stats::from_samples(samples))
+}
+
+/// Benchmarks one day against `input`. Reading the input isn't timed.
+pub fn bench_day(day: &Day, input: &Source, runs: usize, warmup: usize) -> Result<DayBench> {
+    let contents = input.read(day)?;
+    let parse = measure(runs, warmup, || day.solution.parse(&contents))?;
+    let parsed = day.solution.parse(&contents)?;
+    let part = |part| {
+        measure(runs, warmup, || {
+            runner::run_part(day, parsed.as_ref(), part)
+        })
+    };
+
+    Ok(DayBench {
+        year: day.year,
+        day: day.day,
+        input: input.label(),
+        parse,
+        part_one: part(Part::One)?,
+        part_two: part(Part::Two)?,
+    })
+}
+
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub stage: &'static str,
+    pub before_ns: u64,
+    pub after_ns: u64,
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        (self.after_ns as f64 / self.before_ns as f64 - 1.0) * 100.0
+    }
+}
+
+/// Stages whose median got slower by more than `threshold` percent. Days
+/// missing from `previous` are not compared.
+pub fn compare(previous: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+    for day in &current.days {
+        let Some(before) = previous
+            .days
+            .iter()
+            .find(|b| b.year == day.year && b.day == day.day && b.input == day.input)
+        else {
+            continue;
+        };
+        for ((stage, after), (_, before)) in day.stats().into_iter().zip(before.stats()) {
+            let regression = Regression {
+                year: day.year,
+                day: day.day,
+                stage,
+                before_ns: before.median_ns,
+                after_ns: after.median_ns,
+            };
+            if before.median_ns > 0 && regression.percent() > threshold {
+                regressions.push(regression);
+            }
+        }
+    }
+    regressions
+}
+
+pub fn format_duration(ns: u64) -> String {
+    match ns {
+        0..=999 => format!("{}ns", ns),
+        1_000..=999_999 => format!("{:.1}µs", ns as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", ns as f64 / 1e6),
+        _ => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+pub fn print_day(bench: &DayBench) {
+    outln!("{} day {} ({})", bench.year, bench.day, bench.input);
+    for (stage, stats) in bench.stats() {
+        outln!(
+            "  {:<9} min {:>10}  median {:>10}  max {:>10}",
+            stage,
+            format_duration(stats.min_ns),
+            format_duration(stats.median_ns),
+            format_duration(stats.max_ns),
+        );
+    }
+}
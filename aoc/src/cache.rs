@@ -0,0 +1,56 @@
+//! Local copies of puzzle inputs at `<cache_dir>/<year>/<day>/input`, so each
+//! input is downloaded at most once.
+
+use crate::config::Config;
+use crate::remote::{Backend, RemoteError};
+use std::io;
+use std::path::PathBuf;
+
+pub struct InputCache {
+    root: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(root: PathBuf) -> Self {
+        InputCache { root }
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        InputCache::new(config.cache_dir())
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(day.to_string())
+            .join("input")
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> io::Result<Option<String>> {
+        match std::fs::read_to_string(self.path(year, day)) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the cached input, fetching and caching it on a miss. A cached
+    /// input is never fetched again.
+    pub fn get_or_fetch(
+        &self,
+        year: u16,
+        day: u8,
+        backend: &dyn Backend,
+    ) -> Result<String, RemoteError> {
+        if let Some(contents) = self.get(year, day)? {
+            return Ok(contents);
+        }
+        let contents = backend.fetch_input(year, day)?;
+        let path = self.path(year, day);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, &contents)?;
+        Ok(contents)
+    }
+}
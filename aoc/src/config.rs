@@ -0,0 +1,125 @@
+//! User settings for talking to the puzzle site, read from
+//! `$AOC_CONFIG`, or `$XDG_CONFIG_HOME/aoc/config.toml`, or
+//! `~/.config/aoc/config.toml`:
+//!
+//! ```toml
+//! session = "53616c746564..."          # the site's `session` cookie
+//! endpoint = "https://adventofcode.com"
+//! cache_dir = "/home/me/.cache/aoc"
+//! min_request_interval = 5.0            # seconds between requests
+//! offline = false                       # never touch the network
+//! key_file = "/home/me/.config/aoc/input.key"
+//! ```
+//!
+//! Every key is optional. `AOC_SESSION`, `AOC_ENDPOINT`, `AOC_CACHE_DIR` and
+//! `AOC_KEY_FILE` override the file.
+
+use serde::Deserialize;
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+const DEFAULT_MIN_REQUEST_INTERVAL: f64 = 5.0;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub endpoint: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+    pub min_request_interval: Option<f64>,
+    #[serde(default)]
+    pub offline: bool,
+    pub key_file: Option<PathBuf>,
+}
+
+fn home() -> Option<PathBuf> {
+    env::var_os("HOME").map(PathBuf::from)
+}
+
+impl Config {
+    fn dir() -> Option<PathBuf> {
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home().map(|home| home.join(".config")))?;
+        Some(config_home.join("aoc"))
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os("AOC_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+        Some(Config::dir()?.join("config.toml"))
+    }
+
+    /// Loads the config file, if there is one, and applies the environment
+    /// overrides.
+    pub fn load() -> io::Result<Self> {
+        let mut config = match Config::path().map(std::fs::read_to_string) {
+            Some(Ok(contents)) => toml::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Some(Err(e)) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => Config::default(),
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(endpoint) = env::var("AOC_ENDPOINT") {
+            config.endpoint = Some(endpoint);
+        }
+        if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+            config.cache_dir = Some(PathBuf::from(dir));
+        }
+        if let Some(path) = env::var_os("AOC_KEY_FILE") {
+            config.key_file = Some(PathBuf::from(path));
+        }
+        Ok(config)
+    }
+
+    pub fn endpoint(&self) -> &str {
+        self.endpoint
+            .as_deref()
+            .unwrap_or(DEFAULT_ENDPOINT)
+            .trim_end_matches('/')
+    }
+
+    /// The session token, ignoring an empty or blank one.
+    pub fn session(&self) -> Option<&str> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        if let Some(dir) = &self.cache_dir {
+            return dir.clone();
+        }
+        env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home().map(|home| home.join(".cache")))
+            .unwrap_or_else(env::temp_dir)
+            .join("aoc")
+    }
+
+    pub fn min_request_interval(&self) -> Duration {
+        Duration::from_secs_f64(
+            self.min_request_interval
+                .unwrap_or(DEFAULT_MIN_REQUEST_INTERVAL)
+                .max(0.0),
+        )
+    }
+
+    /// The key that encrypted inputs in the repository are sealed with. It
+    /// lives outside the repository so it never gets committed.
+    pub fn key_file(&self) -> PathBuf {
+        if let Some(path) = &self.key_file {
+            return path.clone();
+        }
+        Config::dir()
+            .unwrap_or_else(|| PathBuf::from(".aoc"))
+            .join("input.key")
+    }
+}
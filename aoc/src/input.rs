@@ -0,0 +1,163 @@
+//! Where a day's input comes from. By convention each day's directory holds
+//! its real puzzle input in `input` and example inputs in `sample`, `sample2`,
+//! `sample3`, ... so the runner can find them without being told file names.
+//! Any of them may instead be committed encrypted as `<name>.enc` (see
+//! [`vault`]) and is then decrypted on the fly. A real input that isn't in the
+//! day's directory at all comes from the input cache, which downloads it on
+//! first use.
+
+use crate::cache::InputCache;
+use crate::config::Config;
+use crate::registry::Day;
+use crate::remote;
+use crate::vault;
+use aoc_core::{Error, Result};
+use clap::Args;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+pub const REAL_INPUT: &str = "input";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The day's real puzzle input.
+    Real,
+    /// The n-th sample, counting from 1.
+    Sample(u32),
+    /// An explicit path, resolved against the day's directory first.
+    Path(String),
+    Stdin,
+}
+
+impl Source {
+    /// File name of a sample in the day's directory.
+    pub fn sample_name(n: u32) -> String {
+        if n <= 1 {
+            "sample".to_string()
+        } else {
+            format!("sample{}", n)
+        }
+    }
+
+    /// Short name used in reports and as the key in the answers store.
+    pub fn label(&self) -> String {
+        match self {
+            Source::Real => REAL_INPUT.to_string(),
+            Source::Sample(n) => Source::sample_name(*n),
+            Source::Path(path) => path.clone(),
+            Source::Stdin => "-".to_string(),
+        }
+    }
+
+    /// The `aoc run` arguments that select this source.
+    pub fn args(&self) -> Vec<String> {
+        match self {
+            Source::Real => vec![],
+            Source::Sample(n) => vec!["--sample".to_string(), n.to_string()],
+            Source::Path(path) => vec!["--input".to_string(), path.clone()],
+            Source::Stdin => vec!["--input".to_string(), "-".to_string()],
+        }
+    }
+
+    /// The file this source reads, if it is one.
+    pub fn path(&self, day: &Day) -> Option<PathBuf> {
+        match self {
+            Source::Real => Some(day.path().join(REAL_INPUT)),
+            Source::Sample(n) => Some(day.path().join(Source::sample_name(*n))),
+            Source::Path(path) => Some(day.input_path(path)),
+            Source::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String> {
+        let Some(path) = self.path(day) else {
+            let mut contents = String::new();
+            io::stdin()
+                .read_to_string(&mut contents)
+                .map_err(|source| Error::Io {
+                    path: PathBuf::from("<stdin>"),
+                    source,
+                })?;
+            return Ok(contents);
+        };
+        if !path.is_file() {
+            let sealed = vault::sealed_path(&path);
+            if sealed.is_file() {
+                info!(path = %sealed.display(), "decrypting input");
+                return read_sealed(&sealed);
+            }
+            if *self == Source::Real {
+                info!("no input in the day's directory, using the input cache");
+                return read_cached(day);
+            }
+        }
+        debug!(path = %path.display(), "reading input");
+        std::fs::read_to_string(&path).map_err(|source| Error::Io { path, source })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label())
+    }
+}
+
+fn load_config() -> Result<Config> {
+    Config::load().map_err(|source| Error::Io {
+        path: Config::path().unwrap_or_default(),
+        source,
+    })
+}
+
+fn read_sealed(sealed: &Path) -> Result<String> {
+    let config = load_config()?;
+    vault::read(sealed, &config.key_file()).map_err(|source| Error::Io {
+        path: sealed.to_path_buf(),
+        source,
+    })
+}
+
+fn read_cached(day: &Day) -> Result<String> {
+    let config = load_config()?;
+    let cache = InputCache::from_config(&config);
+    cache
+        .get_or_fetch(day.year, day.day, remote::backend(&config).as_ref())
+        .map_err(|e| Error::Io {
+            path: cache.path(day.year, day.day),
+            source: e.into(),
+        })
+}
+
+/// The samples present in a day's directory, in order.
+pub fn samples(day: &Day) -> Vec<Source> {
+    (1..)
+        .map(Source::Sample)
+        .take_while(|sample| sample.path(day).is_some_and(|path| path.is_file()))
+        .collect()
+}
+
+/// Command-line selection of an input, shared by every command that runs a
+/// single day.
+#[derive(Debug, Clone, Default, Args)]
+pub struct InputArgs {
+    /// Input file (relative to the day's directory, or to the current one),
+    /// or `-` to read standard input
+    #[arg(long, conflicts_with = "sample")]
+    pub input: Option<String>,
+    /// Use the day's n-th sample instead of its real input
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+    pub sample: Option<u32>,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> Source {
+        match (&self.input, self.sample) {
+            (Some(input), _) if input == "-" => Source::Stdin,
+            (Some(input), _) => Source::Path(input.clone()),
+            (None, Some(n)) => Source::Sample(n),
+            (None, None) => Source::Real,
+        }
+    }
+}
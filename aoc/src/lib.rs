@@ -0,0 +1,19 @@
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod config;
+pub mod input;
+pub mod metrics;
+pub mod output;
+pub mod registry;
+pub mod remote;
+pub mod report;
+pub mod runner;
+pub mod safety;
+pub mod samples;
+pub mod scaffold;
+pub mod submit;
+pub mod tui;
+pub mod vault;
+pub mod verify;
+pub mod watch;
@@ -0,0 +1,777 @@
+use aoc::answers::AnswerStore;
+use aoc::bench;
+use aoc::cache::InputCache;
+use aoc::config::Config;
+use aoc::input::{InputArgs, Source, REAL_INPUT};
+use aoc::metrics::{self, Numeric};
+use aoc::outln;
+use aoc::output::{Format, Printer};
+use aoc::registry::{self, Day};
+use aoc::remote;
+use aoc::report;
+use aoc::runner::{self, Event, Part};
+use aoc::safety::{self, RuleArgs};
+use aoc::scaffold;
+use aoc::submit::{self, Outcome, Submission};
+use aoc::tui;
+use aoc::vault::{self, Key};
+use aoc::verify;
+use aoc::watch;
+use aoc_core::Error;
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use tracing::Level;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How to print results
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Show more diagnostics on stderr: -v for progress, -vv for what the
+    /// days log while solving, -vvv for everything
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Only show errors on stderr
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+}
+
+/// The most detailed diagnostics the verbosity flags ask for.
+fn log_level(verbose: u8, quiet: bool) -> Level {
+    match (quiet, verbose) {
+        (true, _) => Level::ERROR,
+        (false, 0) => Level::WARN,
+        (false, 1) => Level::INFO,
+        (false, 2) => Level::DEBUG,
+        (false, _) => Level::TRACE,
+    }
+}
+
+/// Sends diagnostics at `level` and above to stderr.
+fn init_logging(level: Level) {
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, every registered day of a year, or every registered day
+    Run {
+        #[arg(required_unless_present_any = ["all", "only_year"])]
+        year: Option<u16>,
+        /// Day to run; omit when using --all
+        #[arg(required_unless_present_any = ["all", "only_year"], conflicts_with = "all")]
+        day: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Run every registered day of YEAR, or of every year, in order
+        #[arg(long, conflicts_with = "input")]
+        all: bool,
+        /// Run every registered day of this year in order
+        #[arg(
+            long = "year",
+            value_name = "YEAR",
+            conflicts_with_all = ["year", "input"]
+        )]
+        only_year: Option<u16>,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Days and parts to run at once [default: the number of CPUs]
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+    },
+    /// List the registered years and days, and which parts are done
+    List {
+        /// Only this year
+        year: Option<u16>,
+    },
+    /// Check every day's answers against the known answers in answers/<year>.toml
+    Verify {
+        /// Only verify this year
+        year: Option<u16>,
+        /// Store the current answers instead of checking them
+        #[arg(long)]
+        record: bool,
+    },
+    /// Compare two columns of a list the way day 1 reads them, by several metrics
+    Metrics {
+        /// Defaults to day 1's real input
+        #[command(flatten)]
+        input: InputArgs,
+        /// First column to compare, counting from 1
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        left: u16,
+        /// Second column to compare, counting from 1
+        #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u16).range(1..))]
+        right: u16,
+        /// Integer type to read the values as
+        #[arg(long, value_enum, default_value_t = Numeric::Big)]
+        numeric: Numeric,
+    },
+    /// Day 1's similarity score of a list, read a line at a time so it can be
+    /// larger than memory
+    Similarity {
+        /// Defaults to day 1's real input
+        #[command(flatten)]
+        input: InputArgs,
+        /// Column whose values are scored, counting from 1
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        left: u16,
+        /// Column whose values are counted, counting from 1
+        #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u16).range(1..))]
+        right: u16,
+        /// Integer type to read the values and sum the score as
+        #[arg(long, value_enum, default_value_t = Numeric::I128)]
+        numeric: Numeric,
+    },
+    /// Count day 2's safe reports under rules other than the puzzle's
+    Safety {
+        /// Defaults to day 2's real input
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        rules: RuleArgs,
+    },
+    /// Time parsing and each part of one day, or of every day
+    Bench {
+        /// Only benchmark this year
+        year: Option<u16>,
+        /// Only benchmark this day
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// Timed runs per stage
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Untimed runs per stage before timing starts
+        #[arg(long, default_value_t = 2)]
+        warmup: usize,
+        /// Where to write the JSON report [default: target/bench/latest.json]
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Previous JSON report to check for regressions
+        #[arg(long)]
+        compare: Option<PathBuf>,
+        /// Percentage slowdown of a median that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Create a new day from the template and register it with the runner
+    New { year: u16, day: u8 },
+    /// Download puzzle inputs into the local cache (never re-downloads)
+    Fetch {
+        year: u16,
+        /// Only this day; defaults to every registered day of the year
+        day: Option<u8>,
+    },
+    /// Run one part on the real input and submit its answer
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Rebuild and rerun a day, and check its samples, whenever its files change
+    Watch {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Write an HTML page of answers, verification, timings and visualizations
+    Report {
+        /// Only report on this year
+        year: Option<u16>,
+        /// Where to write the page [default: target/report/index.html]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Browse every day's stars, answers and timings, and run days interactively
+    Tui,
+    /// Manage the encrypted copies of real inputs committed to the repository
+    Inputs {
+        #[command(subcommand)]
+        action: InputsAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputsAction {
+    /// Seal each day's `input` into `input.enc`, generating the key if needed
+    Encrypt {
+        /// Only this year
+        year: Option<u16>,
+        /// Only this day
+        #[arg(requires = "year")]
+        day: Option<u8>,
+    },
+    /// Restore each day's `input` from `input.enc`
+    Decrypt {
+        /// Only this year
+        year: Option<u16>,
+        /// Only this day
+        #[arg(requires = "year")]
+        day: Option<u8>,
+    },
+}
+
+/// Runs `days` in parallel, printing results in order. Keeps going after a
+/// failure so one broken day doesn't hide the rest, and returns the exit code
+/// of the first failure.
+fn run_days(
+    days: &[&'static Day],
+    source: &Source,
+    parts: &[Part],
+    jobs: Option<u32>,
+    out: &mut Printer,
+) -> Option<ExitCode> {
+    let jobs = jobs.map_or_else(
+        || thread::available_parallelism().map_or(1, |n| n.get()),
+        |jobs| jobs as usize,
+    );
+    let mut first_failure = None;
+    runner::run_days(days, source, parts, jobs, |event| match event {
+        Event::Day(day) => {
+            if out.is_text() {
+                outln!("--- {} day {} ({}) ---", day.year, day.day, source);
+            }
+        }
+        Event::Part(result) => {
+            if out.is_text() {
+                let part = Part::from_number(result.part).expect("parts are numbered 1 and 2");
+                outln!("Part {}: {}", part, result.answer);
+            } else {
+                out.record(&result);
+            }
+        }
+        Event::Unsolved(_, part) => {
+            // Not a failure, and there's no answer to record
+            if out.is_text() {
+                outln!("Part {}: {}", part, Error::Unsolved);
+            }
+        }
+        Event::Failed(day, err) => {
+            report_error(day, &err);
+            first_failure.get_or_insert_with(|| exit_code(&err));
+        }
+    });
+    first_failure
+}
+
+/// One line per year, with a star per part done in each day's column, the way
+/// the puzzle site shows progress.
+fn print_status(statuses: &[registry::Status]) {
+    outln!(
+        "      {}",
+        (1..=25)
+            .map(|day| format!("{:>3}", day))
+            .collect::<String>()
+    );
+    for year in registry::years() {
+        let days: Vec<_> = statuses.iter().filter(|s| s.year == year).collect();
+        if days.is_empty() {
+            continue;
+        }
+        let mut line = format!("{:<6}", year);
+        let mut stars = 0;
+        for day in 1..=25 {
+            let cell = match days.iter().find(|s| s.day == day) {
+                None => "",
+                Some(status) => {
+                    let done = Part::BOTH.map(|part| status.done(part));
+                    stars += done.iter().filter(|&&d| d).count();
+                    match done {
+                        [true, true] => "**",
+                        [true, false] | [false, true] => "*",
+                        [false, false] => ".",
+                    }
+                }
+            };
+            line.push_str(&format!("{:>3}", cell));
+        }
+        let plural = if days.len() == 1 { "" } else { "s" };
+        outln!("{}  {} day{}, {} stars", line, days.len(), plural, stars);
+    }
+    outln!("\n. = registered, * = one part done, ** = both parts done");
+}
+
+/// The parts a `--part` option selects.
+fn parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![Part::from_number(part).expect("clap only accepts 1 or 2")],
+        None => Part::BOTH.to_vec(),
+    }
+}
+
+/// Distinct exit codes per failure kind, so scripts can tell them apart.
+/// 1 is a usage problem of ours and 2 is reserved for clap's usage errors.
+fn exit_code(err: &Error) -> ExitCode {
+    ExitCode::from(match err {
+        Error::Io { .. } => 3,
+        Error::Parse { .. } => 4,
+        Error::Puzzle(_) | Error::Unsolved => 5,
+    })
+}
+
+fn report_error(day: &Day, err: &Error) {
+    eprintln!("error: {} day {}: {}", day.year, day.day, err);
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let level = log_level(cli.verbose, cli.quiet);
+    // The dashboard shows diagnostics in its own pane instead
+    if !matches!(cli.command, Command::Tui) {
+        init_logging(level);
+    }
+    let mut out = Printer::new(cli.format);
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            input,
+            only_year,
+            part,
+            jobs,
+            ..
+        } => {
+            let days: Vec<&'static Day> = match (year.or(only_year), day) {
+                (Some(year), Some(day)) => {
+                    let Some(day) = registry::find(year, day) else {
+                        eprintln!("No solution registered for {} day {}", year, day);
+                        return ExitCode::FAILURE;
+                    };
+                    vec![day]
+                }
+                (Some(year), None) => registry::days_in_year(year).collect(),
+                (None, _) => registry::DAYS.iter().collect(),
+            };
+            if days.is_empty() {
+                eprintln!(
+                    "No solutions registered for {}",
+                    year.or(only_year).unwrap_or_default()
+                );
+                return ExitCode::FAILURE;
+            }
+            if let Some(code) = run_days(&days, &input.source(), &parts(part), jobs, &mut out) {
+                return code;
+            }
+        }
+        Command::List { year } => {
+            let statuses = match registry::status(year) {
+                Ok(statuses) => statuses,
+                Err(e) => {
+                    eprintln!("error: answers store: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            if statuses.is_empty() {
+                eprintln!("No solutions registered");
+                return ExitCode::FAILURE;
+            }
+            if out.is_text() {
+                print_status(&statuses);
+            } else {
+                statuses.iter().for_each(|status| out.record(status));
+            }
+        }
+        Command::Verify { year, record } => {
+            let days = registry::DAYS
+                .iter()
+                .filter(|day| year.is_none_or(|year| day.year == year));
+            let checks = match verify::verify(days, record) {
+                Ok(checks) => checks,
+                Err(e) => {
+                    eprintln!("error: answers store: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            if out.is_text() {
+                verify::print_table(&checks);
+            } else {
+                for check in &checks {
+                    out.record(&check.record());
+                }
+            }
+            let failed = checks
+                .iter()
+                .any(|check| matches!(check.status, verify::Status::Fail | verify::Status::Error));
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            runs,
+            warmup,
+            output,
+            compare,
+            threshold,
+        } => {
+            if runs == 0 {
+                eprintln!("--runs must be at least 1");
+                return ExitCode::FAILURE;
+            }
+            let previous = match compare.as_deref().map(bench::Report::load).transpose() {
+                Ok(previous) => previous,
+                Err(e) => {
+                    eprintln!("error: cannot read previous report: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let days = registry::DAYS.iter().filter(|d| {
+                year.is_none_or(|year| d.year == year) && day.is_none_or(|day| d.day == day)
+            });
+            let mut report = bench::Report {
+                runs,
+                warmup,
+                days: Vec::new(),
+            };
+            let mut first_failure = None;
+            for day in days {
+                match bench::bench_day(day, &Source::Real, runs, warmup) {
+                    Ok(result) => {
+                        if out.is_text() {
+                            bench::print_day(&result);
+                        } else {
+                            result.records().for_each(|record| out.record(&record));
+                        }
+                        report.days.push(result);
+                    }
+                    Err(err) => {
+                        report_error(day, &err);
+                        first_failure.get_or_insert_with(|| exit_code(&err));
+                    }
+                }
+            }
+
+            let output = output.unwrap_or_else(bench::Report::default_path);
+            if let Err(e) = report.save(&output) {
+                eprintln!("error: cannot write {}: {}", output.display(), e);
+                return ExitCode::FAILURE;
+            }
+            if out.is_text() {
+                outln!("Report written to {}", output.display());
+            }
+
+            if let Some(previous) = previous {
+                let regressions = bench::compare(&previous, &report, threshold);
+                for r in &regressions {
+                    // Keep stdout to the records in the machine-readable formats
+                    let line = format!(
+                        "REGRESSION {} day {} {}: {} -> {} ({:+.1}%)",
+                        r.year,
+                        r.day,
+                        r.stage,
+                        bench::format_duration(r.before_ns),
+                        bench::format_duration(r.after_ns),
+                        r.percent(),
+                    );
+                    if out.is_text() {
+                        outln!("{}", line);
+                    } else {
+                        eprintln!("{}", line);
+                    }
+                }
+                if !regressions.is_empty() {
+                    first_failure.get_or_insert(ExitCode::FAILURE);
+                } else if out.is_text() {
+                    outln!("No regressions above {}%", threshold);
+                }
+            }
+            if let Some(code) = first_failure {
+                return code;
+            }
+        }
+        Command::New { year, day } => match scaffold::new_day(year, day) {
+            Ok(dir) => {
+                outln!("Created {}", dir.display());
+                outln!("Put the example from the puzzle in `sample` and its answers in `sample.answers`.");
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Metrics {
+            input,
+            left,
+            right,
+            numeric,
+        } => {
+            let Some(day) = registry::find(2024, 1) else {
+                eprintln!("No solution registered for 2024 day 1");
+                return ExitCode::FAILURE;
+            };
+            let record = input.source().read(day).and_then(|contents| {
+                metrics::compare(&contents, left.into(), right.into(), numeric)
+            });
+            match record {
+                Ok(record) if out.is_text() => metrics::print(&record),
+                Ok(record) => out.record(&record),
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    return exit_code(&err);
+                }
+            }
+        }
+        Command::Similarity {
+            input,
+            left,
+            right,
+            numeric,
+        } => {
+            let Some(day) = registry::find(2024, 1) else {
+                eprintln!("No solution registered for 2024 day 1");
+                return ExitCode::FAILURE;
+            };
+            match metrics::similarity(day, &input.source(), left.into(), right.into(), numeric) {
+                Ok(record) if out.is_text() => outln!("{}", record.similarity_score),
+                Ok(record) => out.record(&record),
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    return exit_code(&err);
+                }
+            }
+        }
+        Command::Safety { input, rules } => {
+            let rules = match rules.rules() {
+                Ok(rules) => rules,
+                Err(e) => {
+                    eprintln!("error: rules: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let Some(day) = registry::find(2024, 2) else {
+                eprintln!("No solution registered for 2024 day 2");
+                return ExitCode::FAILURE;
+            };
+            match safety::check(day, &input.source(), &rules) {
+                Ok(record) if out.is_text() => safety::print(&rules, &record),
+                Ok(record) => out.record(&record),
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    return exit_code(&err);
+                }
+            }
+        }
+        Command::Fetch { year, day } => {
+            let config = match Config::load() {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("error: config: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let cache = InputCache::from_config(&config);
+            let backend = remote::backend(&config);
+            let days: Vec<u8> = match day {
+                Some(day) => vec![day],
+                None => registry::days_in_year(year).map(|d| d.day).collect(),
+            };
+            for day in days {
+                match cache.get_or_fetch(year, day, backend.as_ref()) {
+                    Ok(_) => outln!("{} day {}: {}", year, day, cache.path(year, day).display()),
+                    Err(e) => {
+                        eprintln!("error: {} day {}: {}", year, day, e);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+        Command::Submit { year, day, part } => {
+            let Some(day) = registry::find(year, day) else {
+                eprintln!("No solution registered for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+            let part = Part::from_number(part).expect("clap only accepts 1 or 2");
+            let answer = match runner::load(day, &Source::Real)
+                .and_then(|parsed| runner::run_part(day, parsed.as_ref(), part))
+            {
+                Ok(answer) => answer,
+                Err(err) => {
+                    report_error(day, &err);
+                    return exit_code(&err);
+                }
+            };
+            outln!("{} day {} part {}: {}", year, day.day, part, answer);
+
+            let config = match Config::load() {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("error: config: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut store = match AnswerStore::load(year) {
+                Ok(store) => store,
+                Err(e) => {
+                    eprintln!("error: answers store: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let backend = remote::backend(&config);
+            let submission =
+                match submit::submit(&mut store, backend.as_ref(), day, part, answer.as_str()) {
+                    Ok(submission) => submission,
+                    Err(e) => {
+                        eprintln!("error: {} day {}: {}", year, day.day, e);
+                        return ExitCode::FAILURE;
+                    }
+                };
+            outln!("{}", submission);
+            if let Submission::Sent(_) = submission {
+                if let Err(e) = store.save() {
+                    eprintln!("error: answers store: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+            let correct = match &submission {
+                Submission::Sent(outcome) => *outcome == Outcome::Correct,
+                Submission::AlreadySolved { answer: known } => known == answer.as_str(),
+                Submission::KnownWrong(_) => false,
+            };
+            if !correct {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Watch {
+            year,
+            day,
+            input,
+            part,
+        } => {
+            let Some(day) = registry::find(year, day) else {
+                eprintln!("No solution registered for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+            let source = input.source();
+            if source == Source::Stdin {
+                eprintln!("aoc watch can't read standard input; use a file");
+                return ExitCode::FAILURE;
+            }
+            let colour = io::stdout().is_terminal();
+            if let Err(e) = watch::watch(day, &source, &parts(part), colour) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Report { year, output } => {
+            let days: Vec<&'static Day> = registry::DAYS
+                .iter()
+                .filter(|day| year.is_none_or(|year| day.year == year))
+                .collect();
+            let checks = match verify::verify(days.iter().copied(), false) {
+                Ok(checks) => checks,
+                Err(e) => {
+                    eprintln!("error: answers store: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let bench = bench::Report::load(&bench::Report::default_path()).ok();
+            let visuals: Vec<_> = days
+                .iter()
+                .filter_map(|&day| {
+                    let visuals = report::visualize(day, &Source::Real)?;
+                    Some((day, visuals.map_err(|e| e.to_string())))
+                })
+                .collect();
+            let html = report::render(&checks, bench.as_ref(), &visuals);
+
+            let output = output
+                .unwrap_or_else(|| registry::workspace_root().join("target/report/index.html"));
+            let written = output
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&output, html));
+            if let Err(e) = written {
+                eprintln!("error: cannot write {}: {}", output.display(), e);
+                return ExitCode::FAILURE;
+            }
+            outln!("Report written to {}", output.display());
+        }
+        Command::Tui => {
+            if let Err(e) = tui::run(level) {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Inputs { action } => {
+            let config = match Config::load() {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("error: config: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let key_file = config.key_file();
+            let (encrypt, year, day) = match action {
+                InputsAction::Encrypt { year, day } => (true, year, day),
+                InputsAction::Decrypt { year, day } => (false, year, day),
+            };
+            let key = if encrypt {
+                Key::load_or_generate(&key_file).map(|(key, generated)| {
+                    if generated {
+                        outln!(
+                            "Generated a new key in {}; keep a copy somewhere safe",
+                            key_file.display()
+                        );
+                    }
+                    key
+                })
+            } else {
+                Key::load(&key_file)
+            };
+            let key = match key {
+                Ok(key) => key,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let days = registry::DAYS.iter().filter(|d| {
+                year.is_none_or(|year| d.year == year) && day.is_none_or(|day| d.day == day)
+            });
+            for day in days {
+                let path = day.path().join(REAL_INPUT);
+                let result = if encrypt && path.is_file() {
+                    vault::encrypt_file(&key, &path).map(Some)
+                } else if !encrypt && vault::sealed_path(&path).is_file() {
+                    vault::decrypt_file(&key, &path).map(|()| Some(path.clone()))
+                } else {
+                    Ok(None)
+                };
+                match result {
+                    Ok(Some(written)) => {
+                        outln!("{} day {}: {}", day.year, day.day, written.display())
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        eprintln!("error: {} day {}: {}", day.year, day.day, e);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,145 @@
+//! `aoc metrics`: comparisons of two columns of numbers besides day 1's, using
+//! day 1's parser so any whitespace- or comma-separated list works. Also
+//! `aoc similarity`, day 1's similarity score of lists too large to load.
+
+use crate::input::Source;
+use crate::outln;
+use crate::registry::Day;
+use aoc_core::{Error, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufReader, Cursor};
+use std::path::Path;
+use y2024_day1::metrics::metrics;
+use y2024_day1::{read_columns, stream_similarity_score, BigInt, Columns, Number};
+
+/// The integer type values are read and summed as. Fixed widths are faster;
+/// `big` can't overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Numeric {
+    I64,
+    U64,
+    I128,
+    Big,
+}
+
+/// The metrics for one pair of columns, for printing or `--format json`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricsRecord {
+    pub rows: usize,
+    /// The compared columns, counting from 1.
+    pub left: usize,
+    pub right: usize,
+    /// Kept as text, as it can be larger than any fixed-width integer.
+    pub squared_distance: String,
+    pub kendall_tau: Option<f64>,
+    pub spearman: Option<f64>,
+    pub jaccard: Option<f64>,
+    pub earth_movers_distance: Option<f64>,
+}
+
+/// Compares columns `left` and `right`, counting from 1, of `contents`, read
+/// as `numeric`.
+pub fn compare(
+    contents: &str,
+    left: usize,
+    right: usize,
+    numeric: Numeric,
+) -> Result<MetricsRecord> {
+    match numeric {
+        Numeric::I64 => compare_as::<i64>(contents, left, right),
+        Numeric::U64 => compare_as::<u64>(contents, left, right),
+        Numeric::I128 => compare_as::<i128>(contents, left, right),
+        Numeric::Big => compare_as::<BigInt>(contents, left, right),
+    }
+}
+
+fn compare_as<T: Number>(contents: &str, left: usize, right: usize) -> Result<MetricsRecord> {
+    let columns: Columns<T> = read_columns(contents)?;
+    let metrics = metrics(&columns, left - 1, right - 1)?;
+    Ok(MetricsRecord {
+        rows: columns.len(),
+        left,
+        right,
+        squared_distance: metrics.squared_distance.to_string(),
+        kendall_tau: metrics.kendall_tau,
+        spearman: metrics.spearman,
+        jaccard: metrics.jaccard,
+        earth_movers_distance: metrics.earth_movers_distance,
+    })
+}
+
+pub fn print(record: &MetricsRecord) {
+    let value = |value: Option<f64>| value.map_or("undefined".to_string(), |v| format!("{:.6}", v));
+    outln!(
+        "Columns {} and {}, {} rows",
+        record.left,
+        record.right,
+        record.rows
+    );
+    outln!("  Squared distance        {}", record.squared_distance);
+    outln!("  Kendall's tau           {}", value(record.kendall_tau));
+    outln!("  Spearman's rho          {}", value(record.spearman));
+    outln!("  Jaccard index           {}", value(record.jaccard));
+    outln!(
+        "  Earth mover's distance  {}",
+        value(record.earth_movers_distance)
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimilarityRecord {
+    pub left: usize,
+    pub right: usize,
+    /// Kept as text, like the squared distance.
+    pub similarity_score: String,
+}
+
+/// Day 1's similarity score of columns `left` and `right`, counting from 1,
+/// read from `source` a line at a time so it needn't fit in memory.
+pub fn similarity(
+    day: &Day,
+    source: &Source,
+    left: usize,
+    right: usize,
+    numeric: Numeric,
+) -> Result<SimilarityRecord> {
+    let score = match numeric {
+        Numeric::I64 => similarity_as::<i64>(day, source, left, right)?.to_string(),
+        Numeric::U64 => similarity_as::<u64>(day, source, left, right)?.to_string(),
+        Numeric::I128 => similarity_as::<i128>(day, source, left, right)?.to_string(),
+        Numeric::Big => similarity_as::<BigInt>(day, source, left, right)?.to_string(),
+    };
+    Ok(SimilarityRecord {
+        left,
+        right,
+        similarity_score: score,
+    })
+}
+
+fn similarity_as<T: Number>(day: &Day, source: &Source, left: usize, right: usize) -> Result<T> {
+    let (left_index, right_index) = (left - 1, right - 1);
+    match source.path(day) {
+        None => stream_similarity_score(
+            io::stdin().lock(),
+            Path::new("<stdin>"),
+            left_index,
+            right_index,
+        ),
+        Some(path) if path.is_file() => {
+            let file = File::open(&path).map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+            stream_similarity_score(BufReader::new(file), &path, left_index, right_index)
+        }
+        // Cached and encrypted inputs are puzzle-sized, so read them whole
+        Some(path) => stream_similarity_score(
+            Cursor::new(source.read(day)?),
+            &path,
+            left_index,
+            right_index,
+        ),
+    }
+}
@@ -0,0 +1,76 @@
+//! The `--format` switch. Text is for people; with `json` each result is
+//! printed as one JSON object per line, and with `csv` as a CSV row under a
+//! header row, for scripts and dashboards.
+
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt;
+use std::io::{self, Stdout, Write};
+use std::process;
+
+/// Like `println!`, but a closed pipe, as in `aoc run --all | head -1`, ends
+/// the program quietly instead of panicking. See [`write_line`].
+#[macro_export]
+macro_rules! outln {
+    ($($arg:tt)*) => {
+        $crate::output::write_line(format_args!($($arg)*))
+    };
+}
+
+/// Writes one line to standard output. When the reader has gone away the
+/// program exits successfully, as nothing more can be printed; any other
+/// error panics, as with `println!`.
+pub fn write_line(args: fmt::Arguments) {
+    let mut stdout = io::stdout().lock();
+    check(writeln!(stdout, "{}", args));
+}
+
+fn check(result: io::Result<()>) {
+    match result {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => process::exit(0),
+        Err(e) => panic!("failed printing to stdout: {}", e),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+/// Prints results as records in the chosen format. Like [`outln!`], it exits
+/// quietly if standard output is a closed pipe and panics on other errors.
+pub struct Printer {
+    format: Format,
+    csv: Option<csv::Writer<Stdout>>,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        let csv = (format == Format::Csv).then(|| csv::Writer::from_writer(io::stdout()));
+        Printer { format, csv }
+    }
+
+    /// Whether results should be printed as free-form text instead of records.
+    pub fn is_text(&self) -> bool {
+        self.format == Format::Text
+    }
+
+    /// Prints one record. In CSV the field names of the first record become
+    /// the header, so every record printed should be of the same type.
+    pub fn record<T: Serialize>(&mut self, record: &T) {
+        match &mut self.csv {
+            Some(csv) => {
+                check(csv.serialize(record).map_err(io::Error::from));
+                check(csv.flush());
+            }
+            None => {
+                let line = serde_json::to_string(record).expect("records serialize to JSON");
+                write_line(format_args!("{}", line));
+            }
+        }
+    }
+}
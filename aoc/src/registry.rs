@@ -0,0 +1,120 @@
+use crate::answers::AnswerStore;
+use crate::input::REAL_INPUT;
+use crate::runner::Part;
+use crate::vault;
+use aoc_core::DynSolution;
+use serde::Serialize;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A single puzzle day that the runner knows how to dispatch to.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Directory of the day's crate, relative to the workspace root.
+    pub dir: &'static str,
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Day {
+    pub fn path(&self) -> PathBuf {
+        workspace_root().join(self.dir)
+    }
+
+    /// Resolves an input file name against the day's directory, falling back to
+    /// the path as given (relative to the current directory) if it isn't there,
+    /// either in plain text or encrypted.
+    pub fn input_path(&self, input: &str) -> PathBuf {
+        let in_day_dir = self.path().join(input);
+        if in_day_dir.exists() || vault::sealed_path(&in_day_dir).exists() {
+            in_day_dir
+        } else {
+            PathBuf::from(input)
+        }
+    }
+}
+
+// Day crates are named `y<year>-day<day>` so every year can have a `day1`
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:path) => {
+        Day {
+            year: $year,
+            day: $day,
+            dir: concat!(stringify!($year), "/rust/day", stringify!($day)),
+            solution: &$solution,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2024, 1, y2024_day1::Day1),
+    day!(2024, 2, y2024_day2::Day2),
+    day!(2024, 3, y2024_day3::Day3),
+    day!(2024, 4, y2024_day4::Day4),
+    day!(2024, 5, y2024_day5::Day5),
+    day!(2024, 6, y2024_day6::Day6),
+    day!(2024, 7, y2024_day7::Day7),
+    day!(2024, 8, y2024_day8::Day8),
+];
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate lives inside the workspace")
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn days_in_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
+
+/// Years with at least one registered day, in order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|d| d.year).collect();
+    years.dedup();
+    years
+}
+
+/// Which parts of a registered day are done, i.e. have a known answer for the
+/// real input in the answers store.
+#[derive(Debug, Clone, Serialize)]
+pub struct Status {
+    pub year: u16,
+    pub day: u8,
+    pub part1: bool,
+    pub part2: bool,
+}
+
+impl Status {
+    pub fn done(&self, part: Part) -> bool {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+/// The status of every registered day in `year`, or in every year.
+pub fn status(year: Option<u16>) -> io::Result<Vec<Status>> {
+    let mut statuses = Vec::new();
+    for year in years()
+        .into_iter()
+        .filter(|y| year.is_none_or(|year| *y == year))
+    {
+        let store = AnswerStore::load(year)?;
+        for day in days_in_year(year) {
+            let expected = store.expected(day.day, REAL_INPUT);
+            let done = |part| expected.is_some_and(|e| e.get(part).is_some());
+            statuses.push(Status {
+                year,
+                day: day.day,
+                part1: done(Part::One),
+                part2: done(Part::Two),
+            });
+        }
+    }
+    Ok(statuses)
+}
@@ -0,0 +1,238 @@
+//! Talking to the puzzle site. The runner only depends on the [`Backend`]
+//! trait, so tests can point an [`HttpBackend`] at a local stub server and
+//! `offline = true` in the config swaps in [`Offline`].
+
+use crate::config::Config;
+use crate::runner::Part;
+use crate::submit::Outcome;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::info;
+
+const USER_AGENT: &str = "github.com/RubenPeeters/advent-of-code by aoc runner";
+
+#[derive(Debug)]
+pub enum RemoteError {
+    /// No session token is configured.
+    MissingToken,
+    /// The site rejected the session token; it has most likely expired.
+    ExpiredToken,
+    /// The puzzle (or its input) isn't available, e.g. it hasn't unlocked yet.
+    NotFound,
+    /// The backend is configured not to use the network.
+    Offline,
+    /// Any other unexpected HTTP status.
+    Status(u16),
+    /// The request didn't complete.
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemoteError::MissingToken => write!(
+                f,
+                "no session token configured; set `session` in {} or AOC_SESSION",
+                Config::path()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "the config file".to_string())
+            ),
+            RemoteError::ExpiredToken => write!(
+                f,
+                "the session token was rejected; it has probably expired, log in again and update it"
+            ),
+            RemoteError::NotFound => write!(f, "the puzzle is not available (yet)"),
+            RemoteError::Offline => write!(f, "offline mode is on and the input is not cached"),
+            RemoteError::Status(code) => write!(f, "unexpected HTTP status {}", code),
+            RemoteError::Transport(e) => write!(f, "request failed: {}", e),
+            RemoteError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for RemoteError {}
+
+impl From<io::Error> for RemoteError {
+    fn from(e: io::Error) -> Self {
+        RemoteError::Io(e)
+    }
+}
+
+impl From<RemoteError> for io::Error {
+    fn from(e: RemoteError) -> Self {
+        match e {
+            RemoteError::Io(e) => e,
+            e => io::Error::other(e),
+        }
+    }
+}
+
+pub trait Backend {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, RemoteError>;
+
+    fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Outcome, RemoteError>;
+}
+
+/// A backend that never touches the network.
+pub struct Offline;
+
+impl Backend for Offline {
+    fn fetch_input(&self, _year: u16, _day: u8) -> Result<String, RemoteError> {
+        Err(RemoteError::Offline)
+    }
+
+    fn submit_answer(
+        &self,
+        _year: u16,
+        _day: u8,
+        _part: Part,
+        _answer: &str,
+    ) -> Result<Outcome, RemoteError> {
+        Err(RemoteError::Offline)
+    }
+}
+
+/// Keeps requests at least `min_interval` apart, across runs, by recording the
+/// time of the last request in a file.
+pub struct RateLimiter {
+    stamp: PathBuf,
+    min_interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(stamp: PathBuf, min_interval: Duration) -> Self {
+        RateLimiter {
+            stamp,
+            min_interval,
+        }
+    }
+
+    fn now() -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    /// Sleeps until the next request is allowed, then records it.
+    pub fn wait(&self) -> io::Result<()> {
+        let last = std::fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|s| s.trim().parse::<f64>().ok())
+            .map(Duration::from_secs_f64);
+        if let Some(last) = last {
+            let next = last + self.min_interval;
+            let now = Self::now();
+            if next > now {
+                info!(wait = ?(next - now), "rate limiting requests to the site");
+                thread::sleep(next - now);
+            }
+        }
+        if let Some(dir) = self.stamp.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.stamp, Self::now().as_secs_f64().to_string())
+    }
+}
+
+pub struct HttpBackend {
+    endpoint: String,
+    session: Option<String>,
+    limiter: RateLimiter,
+    agent: ureq::Agent,
+}
+
+impl HttpBackend {
+    pub fn new(config: &Config) -> Self {
+        HttpBackend {
+            endpoint: config.endpoint().to_string(),
+            session: config.session().map(str::to_string),
+            limiter: RateLimiter::new(
+                config.cache_dir().join(".last-request"),
+                config.min_request_interval(),
+            ),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    fn session(&self) -> Result<&str, RemoteError> {
+        self.session.as_deref().ok_or(RemoteError::MissingToken)
+    }
+
+    /// Sends a rate-limited request with the session cookie and returns the
+    /// response body, mapping error statuses onto [`RemoteError`].
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, RemoteError> {
+        let session = self.session()?;
+        self.limiter.wait()?;
+        info!(method, path, "requesting from {}", self.endpoint);
+
+        let request = self
+            .agent
+            .request(method, &format!("{}{}", self.endpoint, path))
+            .set("Cookie", &format!("session={}", session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => {
+                let mut body = String::new();
+                response.into_reader().read_to_string(&mut body)?;
+                Ok(body)
+            }
+            // The site answers 400 for a bad cookie, with a "log in" hint
+            Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(RemoteError::ExpiredToken),
+            Err(ureq::Error::Status(404, _)) => Err(RemoteError::NotFound),
+            Err(ureq::Error::Status(code, _)) => Err(RemoteError::Status(code)),
+            Err(e) => Err(RemoteError::Transport(e.to_string())),
+        }
+    }
+}
+
+impl Backend for HttpBackend {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, RemoteError> {
+        self.send("GET", &format!("/{}/day/{}/input", year, day), None)
+    }
+
+    fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Outcome, RemoteError> {
+        let level = part.number().to_string();
+        let body = self.send(
+            "POST",
+            &format!("/{}/day/{}/answer", year, day),
+            Some(&[("level", &level), ("answer", answer)]),
+        )?;
+        Ok(Outcome::parse(&body))
+    }
+}
+
+/// The backend the config asks for.
+pub fn backend(config: &Config) -> Box<dyn Backend> {
+    if config.offline {
+        Box::new(Offline)
+    } else {
+        Box::new(HttpBackend::new(config))
+    }
+}
@@ -0,0 +1,406 @@
+//! `aoc report`: a single HTML page with every day's answers and verification
+//! status, the latest bench timings as a bar chart, and pictures of the days
+//! that have something worth drawing. Charts and pictures are inline SVG and
+//! the styles are embedded, so the file can be published on its own.
+
+use crate::bench::{self, Report};
+use crate::input::{Source, REAL_INPUT};
+use crate::registry::Day;
+use crate::verify::{Check, Status};
+use aoc_core::{Grid, Point, Result, Solution};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::f64::consts::TAU;
+use std::fmt::Write;
+
+/// Width of every chart and picture, in pixels.
+const WIDTH: f64 = 640.0;
+
+/// A picture of a day's puzzle, as an SVG element.
+pub struct Visual {
+    pub title: String,
+    pub svg: String,
+}
+
+/// Draws `day` from `source`, for the days that have pictures.
+pub fn visualize(day: &Day, source: &Source) -> Option<Result<Vec<Visual>>> {
+    let draw: fn(&str) -> Result<Vec<Visual>> = match (day.year, day.day) {
+        (2024, 5) => |input| {
+            let queue = y2024_day5::Day5.parse(input)?;
+            Ok(vec![Visual {
+                title: "Page ordering rules".to_string(),
+                svg: graph_svg(&y2024_day5::rules(&queue.map)),
+            }])
+        },
+        (2024, 6) => |input| {
+            let grid = y2024_day6::Day6.parse(input)?;
+            let path = y2024_day6::guard_path(&grid)?;
+            Ok(vec![Visual {
+                title: "The guard's patrol".to_string(),
+                svg: path_svg(&grid, &path),
+            }])
+        },
+        (2024, 8) => |input| {
+            let antennas = y2024_day8::Day8.parse(input)?;
+            Ok(vec![
+                Visual {
+                    title: "Antinodes".to_string(),
+                    svg: antinode_svg(&antennas.grid, &y2024_day8::antinodes(&antennas)),
+                },
+                Visual {
+                    title: "Antinodes with resonant harmonics".to_string(),
+                    svg: antinode_svg(&antennas.grid, &y2024_day8::resonant_antinodes(&antennas)),
+                },
+            ])
+        },
+        _ => return None,
+    };
+    Some(source.read(day).and_then(|input| draw(&input)))
+}
+
+/// A directed graph with its nodes on a circle, ordered so that nodes with
+/// the most outgoing edges come first. Edges from a node to itself aren't
+/// drawn.
+pub fn graph_svg(edges: &[(i32, i32)]) -> String {
+    let mut out_degree: BTreeMap<i32, usize> = BTreeMap::new();
+    for &(from, to) in edges {
+        *out_degree.entry(from).or_default() += 1;
+        out_degree.entry(to).or_default();
+    }
+    let mut nodes: Vec<(i32, usize)> = out_degree.into_iter().collect();
+    nodes.sort_by_key(|&(node, degree)| (std::cmp::Reverse(degree), node));
+
+    let (centre, radius, node_radius) = (WIDTH / 2.0, WIDTH / 2.0 - 30.0, 11.0);
+    let position: HashMap<i32, (f64, f64)> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &(node, _))| {
+            let angle = TAU * i as f64 / nodes.len() as f64 - TAU / 4.0;
+            (
+                node,
+                (centre + radius * angle.cos(), centre + radius * angle.sin()),
+            )
+        })
+        .collect();
+    // Dense graphs are mostly edges, so fade them to keep the nodes readable
+    let opacity = if edges.len() > 100 { 0.12 } else { 0.6 };
+
+    let mut svg = svg_start(WIDTH, WIDTH, WIDTH, WIDTH);
+    svg.push_str(
+        r##"<defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="6" markerHeight="6" orient="auto-start-reverse"><path d="M0,0 L10,5 L0,10 z" fill="#555"/></marker></defs>"##,
+    );
+    for (from, to) in edges {
+        let ((x1, y1), (x2, y2)) = (position[from], position[to]);
+        let length = (x2 - x1).hypot(y2 - y1);
+        // A self-loop has no direction to draw, and would divide by zero
+        if length == 0.0 {
+            continue;
+        }
+        // Stop at the edge of each node's circle
+        let (dx, dy) = (
+            (x2 - x1) / length * node_radius,
+            (y2 - y1) / length * node_radius,
+        );
+        let _ = write!(
+            svg,
+            r##"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="#555" stroke-opacity="{}" marker-end="url(#arrow)"/>"##,
+            x1 + dx,
+            y1 + dy,
+            x2 - dx,
+            y2 - dy,
+            opacity
+        );
+    }
+    for (node, _) in &nodes {
+        let (x, y) = position[node];
+        let _ = write!(
+            svg,
+            r##"<circle cx="{:.1}" cy="{:.1}" r="{}" fill="#fff" stroke="#1a6"/><text x="{:.1}" y="{:.1}" font-size="9" text-anchor="middle" dominant-baseline="central">{}</text>"##,
+            x, y, node_radius, x, y, node
+        );
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// The map with its obstacles, and the path walked across it.
+pub fn path_svg(grid: &Grid<char>, path: &[Point]) -> String {
+    let mut svg = grid_svg_start(grid);
+    for (pos, &cell) in grid.iter() {
+        if cell == '#' {
+            let _ = write!(
+                svg,
+                r##"<rect x="{}" y="{}" width="1" height="1" fill="#333"/>"##,
+                pos.x, pos.y
+            );
+        }
+    }
+    let points: Vec<String> = path
+        .iter()
+        .map(|p| format!("{}.5,{}.5", p.x, p.y))
+        .collect();
+    let _ = write!(
+        svg,
+        r##"<polyline points="{}" fill="none" stroke="#c33" stroke-width="0.4" stroke-linejoin="round"/>"##,
+        points.join(" ")
+    );
+    if let Some(start) = path.first() {
+        let _ = write!(
+            svg,
+            r##"<circle cx="{}.5" cy="{}.5" r="0.8" fill="#1a6"/>"##,
+            start.x, start.y
+        );
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// The antennas on the map, with `antinodes` highlighted.
+pub fn antinode_svg(grid: &Grid<char>, antinodes: &HashSet<Point>) -> String {
+    let mut antinodes: Vec<&Point> = antinodes.iter().collect();
+    antinodes.sort_by_key(|p| (p.y, p.x));
+
+    let mut svg = grid_svg_start(grid);
+    for pos in antinodes {
+        let _ = write!(
+            svg,
+            r##"<rect x="{}" y="{}" width="1" height="1" fill="#fc6"/>"##,
+            pos.x, pos.y
+        );
+    }
+    for (pos, &cell) in grid.iter() {
+        if cell != '.' {
+            let _ = write!(
+                svg,
+                r##"<text x="{}.5" y="{}.5" font-size="0.9" text-anchor="middle" dominant-baseline="central">{}</text>"##,
+                pos.x,
+                pos.y,
+                escape(&cell.to_string())
+            );
+        }
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Opens an SVG drawn in `width` by `height` units and shown `pixels` wide.
+fn svg_start(width: f64, height: f64, pixels: f64, pixel_height: f64) -> String {
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {} {}" width="{}" height="{}">"#,
+        width, height, pixels, pixel_height
+    )
+}
+
+/// Opens an SVG with one unit per grid cell.
+fn grid_svg_start(grid: &Grid<char>) -> String {
+    let (width, height) = (grid.width() as f64, grid.height() as f64);
+    let mut svg = svg_start(width, height, WIDTH, WIDTH * height / width);
+    let _ = write!(
+        svg,
+        r##"<rect width="{}" height="{}" fill="#f6f6f6"/>"##,
+        width, height
+    );
+    svg
+}
+
+/// Bench medians per day and stage, as horizontal bars on a log scale from 1µs
+/// so fast and slow days both show.
+pub fn bench_chart(report: &Report, years: &[u16]) -> String {
+    const LABEL: f64 = 150.0;
+    const BAR: f64 = 12.0;
+    let days: Vec<_> = report
+        .days
+        .iter()
+        .filter(|bench| years.contains(&bench.year))
+        .collect();
+    let slowest = days
+        .iter()
+        .flat_map(|bench| bench.stats())
+        .map(|(_, stats)| stats.median_ns)
+        .max()
+        .unwrap_or(0);
+    let scale = |ns: u64| {
+        let decades = (slowest.max(10_000) as f64 / 1e3).log10();
+        ((ns.max(1_000) as f64 / 1e3).log10() / decades * (WIDTH - LABEL - 70.0)).max(1.0)
+    };
+
+    let height = days.len() as f64 * (3.0 * BAR + 10.0);
+    let mut svg = svg_start(WIDTH, height, WIDTH, height);
+    for (i, bench) in days.iter().enumerate() {
+        let top = i as f64 * (3.0 * BAR + 10.0);
+        let mut label = format!("{} day {}", bench.year, bench.day);
+        if bench.input != REAL_INPUT {
+            let _ = write!(label, " ({})", bench.input);
+        }
+        let _ = write!(
+            svg,
+            r#"<text x="0" y="{:.1}" font-size="12" dominant-baseline="central">{}</text>"#,
+            top + 1.5 * BAR,
+            escape(&label)
+        );
+        for (j, (stage, stats)) in bench.stats().into_iter().enumerate() {
+            let y = top + j as f64 * BAR;
+            let width = scale(stats.median_ns);
+            let _ = write!(
+                svg,
+                r#"<rect class="{}" x="{}" y="{:.1}" width="{:.1}" height="{}"><title>{}: {}</title></rect><text x="{:.1}" y="{:.1}" font-size="10" dominant-baseline="central">{}</text>"#,
+                stage.replace(' ', "-"),
+                LABEL,
+                y + 1.0,
+                width,
+                BAR - 2.0,
+                stage,
+                bench::format_duration(stats.median_ns),
+                LABEL + width + 4.0,
+                y + BAR / 2.0,
+                bench::format_duration(stats.median_ns),
+            );
+        }
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+const STYLE: &str = "
+body { font-family: system-ui, sans-serif; margin: 2em auto; max-width: 60em; color: #222; }
+table { border-collapse: collapse; }
+th, td { padding: 0.3em 0.8em; text-align: left; border-bottom: 1px solid #ddd; }
+td.pass { color: #1a6; }
+td.fail, td.error { color: #c33; font-weight: bold; }
+td.missing, td.unsolved { color: #888; }
+.parse { fill: #bbb; }
+.part-one { fill: #48c; }
+.part-two { fill: #a5d; }
+figure { display: inline-block; margin: 1em 1em 1em 0; }
+figcaption { color: #555; }
+";
+
+/// The whole page: `checks` from `aoc verify`, the latest bench report if
+/// there is one, and each day's pictures or why they couldn't be drawn.
+pub fn render(
+    checks: &[Check],
+    bench: Option<&Report>,
+    visuals: &[(&Day, std::result::Result<Vec<Visual>, String>)],
+) -> String {
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code</title>\n<style>{}</style>\n</head>\n<body>\n<h1>Advent of Code</h1>\n",
+        STYLE
+    );
+
+    let mut years: Vec<u16> = checks.iter().map(|check| check.year).collect();
+    years.dedup();
+    for &year in &years {
+        let _ = write!(
+            html,
+            "<h2>{}</h2>\n<table>\n<tr><th>Day</th><th>Input</th><th>Part one</th><th>Part two</th></tr>\n",
+            year
+        );
+        // Checks come in pairs, part one then part two, for each day and input
+        for pair in checks
+            .iter()
+            .filter(|check| check.year == year)
+            .collect::<Vec<_>>()
+            .chunks(2)
+        {
+            let _ = write!(
+                html,
+                "<tr><td>{}</td><td>{}</td>",
+                pair[0].day,
+                escape(&pair[0].input)
+            );
+            for check in pair {
+                html.push_str(&answer_cell(check));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</table>\n");
+
+        let count = |status| {
+            checks
+                .iter()
+                .filter(|check| check.year == year && check.status == status)
+                .count()
+        };
+        let _ = writeln!(
+            html,
+            "<p>{} passed, {} failed, {} missing, {} errors, {} unsolved</p>",
+            count(Status::Pass),
+            count(Status::Fail),
+            count(Status::Missing),
+            count(Status::Error),
+            count(Status::Unsolved)
+        );
+    }
+
+    html.push_str("<h2>Timings</h2>\n");
+    match bench {
+        Some(report) => {
+            let _ = writeln!(
+                html,
+                "<p>Median of {} runs after {} warm-up runs.</p>\n{}",
+                report.runs,
+                report.warmup,
+                bench_chart(report, &years)
+            );
+        }
+        None => html.push_str("<p>No timings yet; run <code>aoc bench</code>.</p>\n"),
+    }
+
+    if !visuals.is_empty() {
+        html.push_str("<h2>Visualizations</h2>\n");
+    }
+    for (day, result) in visuals {
+        let _ = writeln!(html, "<h3>{} day {}</h3>", day.year, day.day);
+        match result {
+            Ok(visuals) => {
+                for visual in visuals {
+                    let _ = writeln!(
+                        html,
+                        "<figure>{}<figcaption>{}</figcaption></figure>",
+                        visual.svg,
+                        escape(&visual.title)
+                    );
+                }
+            }
+            Err(e) => {
+                let _ = writeln!(html, "<p>Not drawn: {}</p>", escape(e));
+            }
+        }
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn answer_cell(check: &Check) -> String {
+    let class = check.status.to_string().to_lowercase();
+    let (text, title) = match (&check.actual, &check.expected) {
+        (Err(e), _) => ("error".to_string(), e.clone()),
+        (Ok(answer), Some(expected)) if check.status == Status::Fail => {
+            (answer.clone(), format!("expected {}", expected))
+        }
+        (Ok(answer), _) => (answer.clone(), check.status.to_string()),
+    };
+    format!(
+        "<td class=\"{}\" title=\"{}\">{}</td>",
+        class,
+        escape(&title),
+        escape(&text)
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
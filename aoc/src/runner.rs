@@ -0,0 +1,215 @@
+use crate::input::Source;
+use crate::registry::Day;
+use aoc_core::{Answer, Error, Result};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::sync::{mpsc, Arc};
+use std::time::Instant;
+use tracing::{info, info_span, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("one"),
+            Part::Two => f.write_str("two"),
+        }
+    }
+}
+
+/// The span everything logged while running a day is recorded in.
+fn day_span(day: &Day) -> Span {
+    info_span!("day", year = day.year, day = day.day)
+}
+
+/// Reads and parses a day's input.
+pub fn load(day: &Day, source: &Source) -> Result<Box<dyn Any + Send + Sync>> {
+    let _day = day_span(day).entered();
+    let _parse = info_span!("parse", input = %source).entered();
+    day.solution.parse(&source.read(day)?)
+}
+
+pub fn run_part(day: &Day, parsed: &dyn Any, part: Part) -> Result<Answer> {
+    let _day = day_span(day).entered();
+    let _part = info_span!("part", part = part.number()).entered();
+    match part {
+        Part::One => day.solution.part_one(parsed),
+        Part::Two => day.solution.part_two(parsed),
+    }
+}
+
+/// One part's answer, as `aoc run` reports it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed_ns: u64,
+    pub input: String,
+}
+
+/// Runs a part and times it.
+pub fn run_part_timed(
+    day: &Day,
+    source: &Source,
+    parsed: &dyn Any,
+    part: Part,
+) -> Result<PartResult> {
+    let start = Instant::now();
+    let answer = run_part(day, parsed, part)?;
+    let elapsed = start.elapsed();
+    info!(
+        year = day.year,
+        day = day.day,
+        part = part.number(),
+        ?elapsed,
+        "solved"
+    );
+    Ok(PartResult {
+        year: day.year,
+        day: day.day,
+        part: part.number(),
+        answer: answer.to_string(),
+        elapsed_ns: elapsed.as_nanos() as u64,
+        input: source.label(),
+    })
+}
+
+/// What [`run_days`] reports, in the order a sequential run would: per day,
+/// `Day`, then its parts in order, or `Failed` as soon as something fails.
+pub enum Event {
+    Day(&'static Day),
+    Part(PartResult),
+    /// The part isn't written yet; the day's other parts still follow.
+    Unsolved(&'static Day, Part),
+    /// The day failed; nothing more is reported for it.
+    Failed(&'static Day, Error),
+}
+
+/// A finished piece of work: a day that failed to load, or one of its parts.
+enum Done {
+    Failed(Error),
+    Part(Part, Result<PartResult>),
+}
+
+/// What has arrived for a day and hasn't been reported yet.
+#[derive(Default)]
+struct Pending {
+    failed: Option<Error>,
+    parts: [Option<Result<PartResult>>; 2],
+}
+
+/// Runs `parts` of `days` on a pool of `jobs` threads, parsing every day at
+/// once and then running its parts at once. Results are passed to `report` in
+/// order, each as soon as everything before it has been reported, so output is
+/// the same whatever the number of jobs. Each part is timed on the thread that
+/// runs it.
+pub fn run_days(
+    days: &[&'static Day],
+    source: &Source,
+    parts: &[Part],
+    jobs: usize,
+    mut report: impl FnMut(Event),
+) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .expect("cannot start worker threads");
+    let (tx, rx) = mpsc::channel();
+
+    pool.in_place_scope(|scope| {
+        for (i, &day) in days.iter().enumerate() {
+            let tx = tx.clone();
+            scope.spawn(move |scope| match load(day, source) {
+                Err(err) => {
+                    let _ = tx.send((i, Done::Failed(err)));
+                }
+                Ok(parsed) => {
+                    let parsed: Arc<dyn Any + Send + Sync> = Arc::from(parsed);
+                    for &part in parts {
+                        let (tx, parsed) = (tx.clone(), Arc::clone(&parsed));
+                        scope.spawn(move |_| {
+                            let result = run_part_timed(day, source, parsed.as_ref(), part);
+                            let _ = tx.send((i, Done::Part(part, result)));
+                        });
+                    }
+                }
+            });
+        }
+        // The loop below ends once every task has dropped its sender
+        drop(tx);
+
+        let mut pending: Vec<Pending> = days.iter().map(|_| Pending::default()).collect();
+        // The next day to report on, and how far into it reporting has got:
+        // 0 before its `Day` event, then 1 + the index in `parts` of the next
+        // part
+        let (mut next, mut stage) = (0, 0);
+        for (i, done) in rx {
+            match done {
+                Done::Failed(err) => pending[i].failed = Some(err),
+                Done::Part(part, result) => {
+                    pending[i].parts[part.number() as usize - 1] = Some(result)
+                }
+            }
+
+            while next < days.len() {
+                let day = days[next];
+                if stage == 0 {
+                    report(Event::Day(day));
+                    stage = 1;
+                }
+                let failed = if let Some(err) = pending[next].failed.take() {
+                    Some(err)
+                } else if stage <= parts.len() {
+                    let part = parts[stage - 1];
+                    match pending[next].parts[part.number() as usize - 1].take() {
+                        None => break,
+                        Some(Ok(result)) => {
+                            report(Event::Part(result));
+                            stage += 1;
+                            continue;
+                        }
+                        Some(Err(Error::Unsolved)) => {
+                            report(Event::Unsolved(day, part));
+                            stage += 1;
+                            continue;
+                        }
+                        Some(Err(err)) => Some(err),
+                    }
+                } else {
+                    None
+                };
+                if let Some(err) = failed {
+                    report(Event::Failed(day, err));
+                }
+                (next, stage) = (next + 1, 0);
+            }
+        }
+    });
+}
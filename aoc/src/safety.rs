@@ -0,0 +1,124 @@
+//! `aoc safety`: day 2's report check under rules other than the puzzle's,
+//! read from a TOML file and flags:
+//!
+//! ```toml
+//! min_step = 1
+//! max_step = 3
+//! direction = "either"   # increasing, decreasing, either or any
+//! plateaus = false       # whether adjacent levels may be equal
+//! min_length = 2
+//! ```
+//!
+//! Every key is optional and defaults to the puzzle's rule. Flags override the
+//! file.
+
+use crate::input::Source;
+use crate::outln;
+use crate::registry::Day;
+use aoc_core::{Result, Solution};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::PathBuf;
+use y2024_day2::{count_fixable, count_safe, Day2, SafetyRules, Trend};
+
+/// A rules file; see the module docs.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RulesFile {
+    pub min_step: Option<u32>,
+    pub max_step: Option<u32>,
+    pub direction: Option<String>,
+    pub plateaus: Option<bool>,
+    pub min_length: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Args)]
+pub struct RuleArgs {
+    /// TOML file of rules; the flags below override it
+    #[arg(long, value_name = "FILE")]
+    pub rules: Option<PathBuf>,
+    /// Smallest allowed difference between adjacent levels that differ [default: 1]
+    #[arg(long)]
+    pub min_step: Option<u32>,
+    /// Largest allowed difference between adjacent levels [default: 3]
+    #[arg(long)]
+    pub max_step: Option<u32>,
+    /// increasing, decreasing, either or any [default: either]
+    #[arg(long)]
+    pub direction: Option<Trend>,
+    /// Whether adjacent levels may be equal [default: false]
+    #[arg(long)]
+    pub plateaus: Option<bool>,
+    /// Fewest levels in a safe report [default: 2]
+    #[arg(long)]
+    pub min_length: Option<usize>,
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+impl RuleArgs {
+    /// The puzzle's rules, overridden by the rules file and then the flags.
+    pub fn rules(&self) -> io::Result<SafetyRules> {
+        let file = match &self.rules {
+            Some(path) => {
+                let contents = std::fs::read_to_string(path)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+                toml::from_str(&contents)
+                    .map_err(|e| invalid(format!("{}: {}", path.display(), e)))?
+            }
+            None => RulesFile::default(),
+        };
+        let file_direction = file
+            .direction
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(invalid)?;
+
+        let default = SafetyRules::default();
+        let rules = SafetyRules {
+            min_step: self.min_step.or(file.min_step).unwrap_or(default.min_step),
+            max_step: self.max_step.or(file.max_step).unwrap_or(default.max_step),
+            direction: self
+                .direction
+                .or(file_direction)
+                .unwrap_or(default.direction),
+            plateaus: self.plateaus.or(file.plateaus).unwrap_or(default.plateaus),
+            min_length: self
+                .min_length
+                .or(file.min_length)
+                .unwrap_or(default.min_length),
+        };
+        rules.check().map_err(|e| invalid(e.to_string()))?;
+        Ok(rules)
+    }
+}
+
+/// How many of an input's reports are safe, for `--format json` or `csv`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SafetyRecord {
+    pub reports: usize,
+    pub safe: usize,
+    /// Safe once at most one level is removed.
+    pub fixable: usize,
+}
+
+/// Checks every report read from `source` against `rules`. `day` is day 2,
+/// whose directory `source` is looked up in.
+pub fn check(day: &Day, source: &Source, rules: &SafetyRules) -> Result<SafetyRecord> {
+    let reports = Day2.parse(&source.read(day)?)?;
+    Ok(SafetyRecord {
+        reports: reports.len(),
+        safe: count_safe(&reports, rules),
+        fixable: count_fixable(&reports, rules),
+    })
+}
+
+pub fn print(rules: &SafetyRules, record: &SafetyRecord) {
+    outln!("Rules: {}", rules);
+    outln!("Safe: {} of {} reports", record.safe, record.reports);
+    outln!("Safe with one level removed: {}", record.fixable);
+}
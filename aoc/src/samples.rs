@@ -0,0 +1,55 @@
+//! Sample inputs live next to a day's code (e.g. `2024/rust/day5/sample`, see
+//! [`crate::input`]) and carry their expected answers in a sidecar TOML file with the same name plus
+//! `.answers`:
+//!
+//! ```toml
+//! part1 = "143"
+//! part2 = "123"
+//! ```
+//!
+//! Either part may be left out, e.g. for a sample that only illustrates part
+//! two. `aoc/build.rs` turns every sidecar into a test.
+
+use crate::answers::Expected;
+use crate::input::Source;
+use crate::registry::{self, Day};
+use crate::runner::{self, Part};
+use aoc_core::Error;
+use std::io;
+
+const SIDECAR_EXTENSION: &str = "answers";
+
+pub fn expected(day: &Day, sample: &str) -> io::Result<Expected> {
+    let path = day.path().join(format!("{}.{}", sample, SIDECAR_EXTENSION));
+    let contents = std::fs::read_to_string(path)?;
+    toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Runs `sample` through the day and asserts every recorded answer. Called by
+/// the generated sample tests.
+pub fn check(year: u16, day: u8, sample: &str) {
+    let day = registry::find(year, day)
+        .unwrap_or_else(|| panic!("no solution registered for {} day {}", year, day));
+    let expected = expected(day, sample)
+        .unwrap_or_else(|e| panic!("cannot read answers for sample {:?}: {}", sample, e));
+    let parsed = runner::load(day, &Source::Path(sample.to_string()))
+        .unwrap_or_else(|e| panic!("sample {:?}: {}", sample, e));
+
+    for part in Part::BOTH {
+        if let Some(expected) = expected.get(part) {
+            let answer = match runner::run_part(day, parsed.as_ref(), part) {
+                Ok(answer) => answer,
+                // Its answer can be recorded before the part is written
+                Err(Error::Unsolved) => continue,
+                Err(e) => panic!("sample {:?} part {}: {}", sample, part, e),
+            };
+            assert_eq!(
+                answer.as_str(),
+                expected,
+                "sample {:?} part {}",
+                sample,
+                part
+            );
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! `aoc new`: creates a day's crate from `aoc/templates/` and registers it with
+//! the runner.
+
+use crate::registry::{self, workspace_root};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const SAMPLE_ANSWERS: &str = include_str!("../templates/sample.answers.tmpl");
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Creates `<year>/rust/day<day>` with a stub solution, an empty sample and
+/// its answers sidecar, then adds the crate to the runner's dependencies and
+/// registry. Returns the new day's directory.
+pub fn new_day(year: u16, day: u8) -> io::Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(invalid(format!(
+            "day must be between 1 and 25, got {}",
+            day
+        )));
+    }
+    if registry::find(year, day).is_some() {
+        return Err(invalid(format!(
+            "{} day {} is already registered",
+            year, day
+        )));
+    }
+    let root = workspace_root();
+    let dir = root
+        .join(year.to_string())
+        .join("rust")
+        .join(format!("day{}", day));
+    if dir.exists() {
+        return Err(invalid(format!("{} already exists", dir.display())));
+    }
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), render(CARGO_TOML, year, day))?;
+    fs::write(dir.join("src/lib.rs"), render(LIB_RS, year, day))?;
+    fs::write(dir.join("sample"), "")?;
+    fs::write(
+        dir.join("sample.answers"),
+        render(SAMPLE_ANSWERS, year, day),
+    )?;
+
+    register_dependency(&root.join("aoc/Cargo.toml"), year, day)?;
+    register_day(&root.join("aoc/src/registry.rs"), year, day)?;
+    Ok(dir)
+}
+
+fn register_dependency(manifest: &Path, year: u16, day: u8) -> io::Result<()> {
+    let contents = fs::read_to_string(manifest)?;
+    fs::write(manifest, add_dependency(&contents, year, day)?)
+}
+
+fn register_day(registry: &Path, year: u16, day: u8) -> io::Result<()> {
+    let contents = fs::read_to_string(registry)?;
+    fs::write(registry, add_day(&contents, year, day)?)
+}
+
+/// Adds `y<year>-day<day> = { path = ... }` to the `[dependencies]` of the
+/// runner's `Cargo.toml`, given as `manifest`, after the last day crate there,
+/// or else at the end of the table.
+pub fn add_dependency(manifest: &str, year: u16, day: u8) -> io::Result<String> {
+    let mut lines: Vec<String> = manifest.lines().map(str::to_string).collect();
+    let name = format!("y{}-day{}", year, day);
+    let line = format!(
+        "{name} = {{ path = \"../{year}/rust/day{day}\" }}",
+        name = name,
+        day = day,
+        year = year
+    );
+    let start = lines
+        .iter()
+        .position(|l| l.trim() == "[dependencies]")
+        .ok_or_else(|| invalid("no [dependencies] table in aoc/Cargo.toml".to_string()))?;
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .map_or(lines.len(), |i| start + 1 + i);
+    if lines[start + 1..end]
+        .iter()
+        .any(|l| l.split('=').next().map(str::trim) == Some(name.as_str()))
+    {
+        return Err(invalid(format!(
+            "{} is already a dependency in aoc/Cargo.toml",
+            name
+        )));
+    }
+    let at = (start + 1..end)
+        .rfind(|&i| lines[i].contains("/rust/day") && lines[i].contains("path ="))
+        .or_else(|| (start + 1..end).rfind(|&i| !lines[i].trim().is_empty()))
+        .map_or(start + 1, |i| i + 1);
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds a `day!(...)` entry to the `DAYS` table of `registry.rs`, given as
+/// `registry`, keeping it ordered by year and day.
+pub fn add_day(registry: &str, year: u16, day: u8) -> io::Result<String> {
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("pub const DAYS"))
+        .ok_or_else(|| invalid("no DAYS table in registry.rs".to_string()))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l == "];")
+            .ok_or_else(|| invalid("unterminated DAYS table in registry.rs".to_string()))?;
+
+    // Entries look like `    day!(2024, 5, y2024_day5::Day5),`
+    let key = |line: &str| -> Option<(u16, u8)> {
+        let args = line.trim().strip_prefix("day!(")?;
+        let mut parts = args.split(',').map(str::trim);
+        Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+    };
+    if (start + 1..end).any(|i| key(&lines[i]) == Some((year, day))) {
+        return Err(invalid(format!(
+            "{} day {} is already in registry.rs",
+            year, day
+        )));
+    }
+    let at = (start + 1..end)
+        .find(|&i| key(&lines[i]).is_some_and(|k| k > (year, day)))
+        .unwrap_or(end);
+    lines.insert(
+        at,
+        format!(
+            "    day!({year}, {day}, y{year}_day{day}::Day{day}),",
+            year = year,
+            day = day
+        ),
+    );
+    Ok(lines.join("\n") + "\n")
+}
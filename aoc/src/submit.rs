@@ -0,0 +1,144 @@
+//! Submitting answers to the puzzle site and remembering what it said, so a
+//! known answer is never sent twice.
+
+use crate::answers::{AnswerStore, Hint, Rejected};
+use crate::input::REAL_INPUT;
+use crate::registry::Day;
+use crate::remote::{Backend, RemoteError};
+use crate::runner::Part;
+use std::fmt::{self, Display};
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous answer; `wait` is the site's
+    /// "You have ... left to wait", if it said.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part is already solved, or part one isn't solved yet.
+    WrongLevel,
+    /// A response none of the above patterns matched.
+    Unrecognized,
+}
+
+impl Outcome {
+    /// Reads the outcome from the text of the site's response page.
+    pub fn parse(body: &str) -> Outcome {
+        if body.contains("That's the right answer") {
+            Outcome::Correct
+        } else if body.contains("That's not the right answer") {
+            if body.contains("too high") {
+                Outcome::TooHigh
+            } else if body.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            }
+        } else if body.contains("You gave an answer too recently") {
+            let wait = body
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Outcome::RateLimited { wait }
+        } else if body.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unrecognized
+        }
+    }
+
+    /// The rejection to remember, for the outcomes that are one.
+    fn rejection(&self, answer: &str) -> Option<Rejected> {
+        let hint = match self {
+            Outcome::Incorrect => None,
+            Outcome::TooHigh => Some(Hint::TooHigh),
+            Outcome::TooLow => Some(Hint::TooLow),
+            _ => return None,
+        };
+        Some(Rejected {
+            answer: answer.to_string(),
+            hint,
+        })
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => f.write_str("correct"),
+            Outcome::Incorrect => f.write_str("incorrect"),
+            Outcome::TooHigh => f.write_str("incorrect, too high"),
+            Outcome::TooLow => f.write_str("incorrect, too low"),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, try again in {}", wait)
+            }
+            Outcome::RateLimited { wait: None } => f.write_str("rate limited, try again later"),
+            Outcome::WrongLevel => f.write_str("not accepted for this part; is it already solved?"),
+            Outcome::Unrecognized => f.write_str("unrecognized response from the site"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    /// The answer was sent and this is what the site said.
+    Sent(Outcome),
+    /// The store already has this part's correct answer, so nothing was sent.
+    AlreadySolved { answer: String },
+    /// An earlier rejection proves the answer wrong, so nothing was sent.
+    KnownWrong(Rejected),
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Submission::Sent(outcome) => write!(f, "{}", outcome),
+            Submission::AlreadySolved { answer } => {
+                write!(f, "not submitted; already solved with {}", answer)
+            }
+            Submission::KnownWrong(rejected) => {
+                write!(f, "not submitted; {} was already rejected", rejected.answer)?;
+                match rejected.hint {
+                    Some(Hint::TooHigh) => f.write_str(" as too high"),
+                    Some(Hint::TooLow) => f.write_str(" as too low"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Submits `answer` for the real input of `day`, unless the store already
+/// settles it, and records a correct or wrong outcome in the store. The
+/// caller saves the store.
+pub fn submit(
+    store: &mut AnswerStore,
+    backend: &dyn Backend,
+    day: &Day,
+    part: Part,
+    answer: &str,
+) -> Result<Submission, RemoteError> {
+    if let Some(known) = store.expected(day.day, REAL_INPUT) {
+        if let Some(correct) = known.get(part) {
+            return Ok(Submission::AlreadySolved {
+                answer: correct.to_string(),
+            });
+        }
+        if let Some(rejected) = known.ruled_out_by(part, answer) {
+            return Ok(Submission::KnownWrong(rejected.clone()));
+        }
+    }
+
+    let outcome = backend.submit_answer(day.year, day.day, part, answer)?;
+    if outcome == Outcome::Correct {
+        store.record(day.day, REAL_INPUT, part, answer.to_string());
+    } else if let Some(rejected) = outcome.rejection(answer) {
+        store.reject(day.day, REAL_INPUT, part, rejected);
+    }
+    Ok(Submission::Sent(outcome))
+}
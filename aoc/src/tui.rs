@@ -0,0 +1,394 @@
+//! `aoc tui`: a dashboard of every registered day with its stars, the answers
+//! from this session checked against the answers store, and timings from the
+//! latest bench report. Days run in the background through the shared runner,
+//! and everything they log shows up in the output pane.
+
+use crate::answers::{AnswerStore, Expected};
+use crate::bench::{self, DayBench, Report};
+use crate::input::{Source, REAL_INPUT};
+use crate::registry::{self, Day};
+use crate::runner::{self, Part, PartResult};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState};
+use ratatui::Frame;
+use std::collections::btree_map::{BTreeMap, Entry};
+use std::io::{self, Write};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tracing::Level;
+
+/// Lines kept in the output pane.
+const LOG_LINES: usize = 500;
+
+#[derive(Debug, Clone)]
+pub enum PartState {
+    NotRun,
+    Running,
+    Done(PartResult),
+    Failed(String),
+}
+
+/// How a part's latest answer compares with the answers store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// There is no known answer to compare with.
+    Unknown,
+}
+
+pub struct DayRow {
+    pub day: &'static Day,
+    pub expected: Expected,
+    pub bench: Option<DayBench>,
+    pub parts: [PartState; 2],
+}
+
+impl DayRow {
+    pub fn state(&self, part: Part) -> &PartState {
+        &self.parts[part.number() as usize - 1]
+    }
+
+    fn state_mut(&mut self, part: Part) -> &mut PartState {
+        &mut self.parts[part.number() as usize - 1]
+    }
+
+    /// Parts with a known answer, the way the puzzle site counts them.
+    pub fn stars(&self) -> usize {
+        Part::BOTH
+            .iter()
+            .filter(|&&part| self.expected.get(part).is_some())
+            .count()
+    }
+
+    pub fn verdict(&self, part: Part) -> Option<Verdict> {
+        let PartState::Done(result) = self.state(part) else {
+            return None;
+        };
+        Some(match self.expected.get(part) {
+            Some(expected) if expected == result.answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        })
+    }
+}
+
+/// A day and parts to run in the background.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub row: usize,
+    pub parts: Vec<Part>,
+}
+
+/// What a background run sends back.
+#[derive(Debug)]
+pub enum Message {
+    Part {
+        row: usize,
+        part: Part,
+        result: Result<PartResult, String>,
+    },
+}
+
+pub struct App {
+    pub rows: Vec<DayRow>,
+    pub selected: usize,
+    pub log: Vec<String>,
+    pub quit: bool,
+}
+
+impl App {
+    pub fn new(rows: Vec<DayRow>) -> Self {
+        App {
+            rows,
+            selected: 0,
+            log: Vec::new(),
+            quit: false,
+        }
+    }
+
+    /// Every registered day, with its known answers and latest bench timings.
+    pub fn load() -> io::Result<Self> {
+        let report = Report::load(&Report::default_path()).ok();
+        let mut stores = BTreeMap::new();
+        let mut rows = Vec::new();
+        for day in registry::DAYS {
+            let store = match stores.entry(day.year) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(AnswerStore::load(day.year)?),
+            };
+            let bench = report.as_ref().and_then(|report| {
+                report
+                    .days
+                    .iter()
+                    .find(|b| b.year == day.year && b.day == day.day && b.input == REAL_INPUT)
+                    .cloned()
+            });
+            rows.push(DayRow {
+                day,
+                expected: store
+                    .expected(day.day, REAL_INPUT)
+                    .cloned()
+                    .unwrap_or_default(),
+                bench,
+                parts: [PartState::NotRun, PartState::NotRun],
+            });
+        }
+        Ok(App::new(rows))
+    }
+
+    /// Updates the selection or returns what to run for a key press.
+    pub fn handle_key(&mut self, key: KeyCode) -> Vec<Request> {
+        let last = self.rows.len().saturating_sub(1);
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(last),
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Home | KeyCode::Char('g') => self.selected = 0,
+            KeyCode::End | KeyCode::Char('G') => self.selected = last,
+            KeyCode::Enter | KeyCode::Char('r') => return self.request(self.selected, &Part::BOTH),
+            KeyCode::Char('1') => return self.request(self.selected, &[Part::One]),
+            KeyCode::Char('2') => return self.request(self.selected, &[Part::Two]),
+            KeyCode::Char('a') => {
+                return (0..self.rows.len())
+                    .flat_map(|row| self.request(row, &Part::BOTH))
+                    .collect()
+            }
+            _ => {}
+        }
+        Vec::new()
+    }
+
+    /// Marks the parts as running, skipping any that already are.
+    fn request(&mut self, row: usize, parts: &[Part]) -> Vec<Request> {
+        let Some(day_row) = self.rows.get_mut(row) else {
+            return Vec::new();
+        };
+        let parts: Vec<Part> = parts
+            .iter()
+            .copied()
+            .filter(|&part| !matches!(day_row.state(part), PartState::Running))
+            .collect();
+        if parts.is_empty() {
+            return Vec::new();
+        }
+        for &part in &parts {
+            *day_row.state_mut(part) = PartState::Running;
+        }
+        vec![Request { row, parts }]
+    }
+
+    pub fn apply(&mut self, message: Message) {
+        let Message::Part { row, part, result } = message;
+        let day = self.rows[row].day;
+        let label = format!("{} day {} part {}", day.year, day.day, part);
+        let (line, state) = match result {
+            Ok(result) => (
+                format!(
+                    "{}: {} in {}",
+                    label,
+                    result.answer,
+                    bench::format_duration(result.elapsed_ns)
+                ),
+                PartState::Done(result),
+            ),
+            Err(err) => (format!("{}: error: {}", label, err), PartState::Failed(err)),
+        };
+        *self.rows[row].state_mut(part) = state;
+        self.push_log(line);
+    }
+
+    pub fn push_log(&mut self, line: String) {
+        self.log.push(line);
+        if self.log.len() > LOG_LINES {
+            self.log.drain(..self.log.len() - LOG_LINES);
+        }
+    }
+}
+
+/// Runs a request on its own thread, sending each part's result back.
+fn spawn(app: &App, request: Request, tx: &Sender<Message>) {
+    let day = app.rows[request.row].day;
+    let tx = tx.clone();
+    thread::spawn(move || {
+        let parsed = runner::load(day, &Source::Real);
+        for part in request.parts {
+            let result = match &parsed {
+                Ok(parsed) => runner::run_part_timed(day, &Source::Real, parsed.as_ref(), part)
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            let message = Message::Part {
+                row: request.row,
+                part,
+                result,
+            };
+            if tx.send(message).is_err() {
+                return;
+            }
+        }
+    });
+}
+
+fn part_cell(row: &DayRow, part: Part) -> Cell<'static> {
+    match row.state(part) {
+        PartState::NotRun => Cell::from("-".dark_gray()),
+        PartState::Running => Cell::from("running…".yellow()),
+        PartState::Failed(_) => Cell::from("error".red()),
+        PartState::Done(result) => {
+            let answer = Span::raw(result.answer.clone());
+            let mark = match row.verdict(part) {
+                Some(Verdict::Pass) => " ✓".green(),
+                Some(Verdict::Fail) => " ✗".red(),
+                _ => " ?".yellow(),
+            };
+            Cell::from(Line::from(vec![answer, mark]))
+        }
+    }
+}
+
+fn bench_cell(row: &DayRow) -> Cell<'static> {
+    match &row.bench {
+        Some(bench) => Cell::from(format!(
+            "{} / {} / {}",
+            bench::format_duration(bench.parse.median_ns),
+            bench::format_duration(bench.part_one.median_ns),
+            bench::format_duration(bench.part_two.median_ns),
+        )),
+        None => Cell::from("-".dark_gray()),
+    }
+}
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [days, output, help] = Layout::vertical([
+        Constraint::Min(6),
+        Constraint::Length(10),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let header = Row::new([
+        "Year",
+        "Day",
+        "Stars",
+        "Part one",
+        "Part two",
+        "Bench (parse / one / two)",
+    ])
+    .style(Style::new().add_modifier(Modifier::BOLD));
+    let rows = app.rows.iter().map(|row| {
+        Row::new([
+            Cell::from(row.day.year.to_string()),
+            Cell::from(row.day.day.to_string()),
+            Cell::from("*".repeat(row.stars()).yellow()),
+            part_cell(row, Part::One),
+            part_cell(row, Part::Two),
+            bench_cell(row),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(4),
+            Constraint::Length(3),
+            Constraint::Length(5),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(32),
+        ],
+    )
+    .header(header)
+    .block(Block::bordered().title(" Advent of Code "))
+    .row_highlight_style(Style::new().bg(Color::DarkGray));
+    let mut state = TableState::new().with_selected(Some(app.selected));
+    frame.render_stateful_widget(table, days, &mut state);
+
+    let visible = output.height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = app.log[app.log.len().saturating_sub(visible)..]
+        .iter()
+        .map(|line| Line::raw(line.as_str()))
+        .collect();
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" Output ")),
+        output,
+    );
+
+    frame.render_widget(
+        Line::from(" ↑/↓ select  enter run  1/2 run one part  a run all  q quit ".dark_gray()),
+        help,
+    );
+}
+
+/// Collects formatted log lines for the output pane.
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl LogBuffer {
+    fn take_lines(&self) -> Vec<String> {
+        let mut buffer = self.0.lock().unwrap();
+        let Some(end) = buffer.iter().rposition(|&b| b == b'\n') else {
+            return Vec::new();
+        };
+        let complete: Vec<u8> = buffer.drain(..=end).collect();
+        String::from_utf8_lossy(&complete)
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the dashboard until the user quits. Logs at `level` and above go to
+/// the output pane.
+pub fn run(level: Level) -> io::Result<()> {
+    let mut app = App::load()?;
+    let logs = LogBuffer::default();
+    let writer = logs.clone();
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .without_time()
+        .init();
+    let (tx, rx): (Sender<Message>, Receiver<Message>) = mpsc::channel();
+
+    let mut terminal = ratatui::init();
+    let result = (|| -> io::Result<()> {
+        while !app.quit {
+            terminal.draw(|frame| draw(frame, &app))?;
+            if event::poll(Duration::from_millis(100))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        for request in app.handle_key(key.code) {
+                            spawn(&app, request, &tx);
+                        }
+                    }
+                }
+            }
+            for line in logs.take_lines() {
+                app.push_log(line);
+            }
+            while let Ok(message) = rx.try_recv() {
+                app.apply(message);
+            }
+        }
+        Ok(())
+    })();
+    ratatui::restore();
+    result
+}
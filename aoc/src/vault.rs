@@ -0,0 +1,129 @@
+//! Encrypted copies of puzzle inputs, so they can be committed without being
+//! published. `input.enc` sits next to where `input` would be and is sealed
+//! with XChaCha20-Poly1305 under a key kept outside the repository (see
+//! [`Config::key_file`](crate::config::Config::key_file)).
+//!
+//! A sealed file is `MAGIC`, a random 24-byte nonce, then the ciphertext.
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::XChaCha20Poly1305;
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const EXTENSION: &str = "enc";
+const MAGIC: &[u8] = b"aoc-enc1";
+const NONCE_LEN: usize = 24;
+
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Key(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    /// Reads a key file: 64 hex digits, surrounding whitespace ignored.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("key file {}: {}", path.display(), e)))?;
+        let hex = contents.trim();
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not a key (expected 64 hex digits)", path.display()),
+            )
+        };
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(Key(key))
+    }
+
+    /// Writes the key file, readable only by its owner where that's supported.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut hex = String::with_capacity(65);
+        for byte in self.0 {
+            write!(hex, "{:02x}", byte).unwrap();
+        }
+        hex.push('\n');
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        io::Write::write_all(&mut options.open(path)?, hex.as_bytes())
+    }
+
+    /// Loads the key, generating and saving a new one if there isn't one yet.
+    /// The flag says whether it was generated.
+    pub fn load_or_generate(path: &Path) -> io::Result<(Self, bool)> {
+        if path.exists() {
+            return Ok((Key::load(path)?, false));
+        }
+        let key = Key::generate();
+        key.save(path)?;
+        Ok((key, true))
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+pub fn seal(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, plaintext)
+        .expect("encrypting into a Vec cannot fail");
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn open(key: &Key, sealed: &[u8]) -> io::Result<Vec<u8>> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    let rest = sealed
+        .strip_prefix(MAGIC)
+        .ok_or_else(|| invalid("not an encrypted input"))?;
+    if rest.len() < NONCE_LEN {
+        return Err(invalid("encrypted input is truncated"));
+    }
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    key.cipher()
+        .decrypt(nonce.into(), ciphertext)
+        .map_err(|_| invalid("cannot decrypt input: wrong key or corrupted file"))
+}
+
+/// Where the sealed copy of `path` lives: the same name plus `.enc`.
+pub fn sealed_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Reads and decrypts a sealed input.
+pub fn read(sealed: &Path, key_file: &Path) -> io::Result<String> {
+    let key = Key::load(key_file)?;
+    let plaintext = open(&key, &std::fs::read(sealed)?)?;
+    String::from_utf8(plaintext).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Seals `path` into its `.enc` sibling, replacing an older sealed copy.
+pub fn encrypt_file(key: &Key, path: &Path) -> io::Result<PathBuf> {
+    let sealed = sealed_path(path);
+    std::fs::write(&sealed, seal(key, &std::fs::read(path)?))?;
+    Ok(sealed)
+}
+
+/// Restores `path` from its `.enc` sibling.
+pub fn decrypt_file(key: &Key, path: &Path) -> io::Result<()> {
+    let plaintext = open(key, &std::fs::read(sealed_path(path))?)?;
+    std::fs::write(path, plaintext)
+}
@@ -0,0 +1,201 @@
+use crate::answers::AnswerStore;
+use crate::input::{Source, REAL_INPUT};
+use crate::outln;
+use crate::registry::Day;
+use crate::runner::{self, Part};
+use aoc_core::Error;
+use serde::Serialize;
+use std::fmt::{self, Display};
+use std::io;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// No answer is recorded for this input and part.
+    Missing,
+    /// The day failed before producing an answer.
+    Error,
+    /// The part isn't written yet.
+    Unsolved,
+    /// `--record` stored the answer.
+    Recorded,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Error => "ERROR",
+            Status::Unsolved => "unsolved",
+            Status::Recorded => "recorded",
+        })
+    }
+}
+
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub expected: Option<String>,
+    /// The answer, or the error message if the day failed.
+    pub actual: Result<String, String>,
+    pub status: Status,
+}
+
+/// A [`Check`] flattened for `--format json` or `csv`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckRecord {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub part: u8,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub error: Option<String>,
+    pub status: String,
+}
+
+impl Check {
+    pub fn record(&self) -> CheckRecord {
+        CheckRecord {
+            year: self.year,
+            day: self.day,
+            input: self.input.clone(),
+            part: self.part.number(),
+            expected: self.expected.clone(),
+            actual: self.actual.clone().ok(),
+            error: self.actual.clone().err(),
+            status: self.status.to_string().to_lowercase(),
+        }
+    }
+}
+
+/// Runs every day in `days` against its real input and any other inputs with
+/// recorded answers. With `record`, successful answers are written back
+/// to the store instead of being compared.
+pub fn verify<'a>(days: impl IntoIterator<Item = &'a Day>, record: bool) -> io::Result<Vec<Check>> {
+    let mut checks = Vec::new();
+    let mut store: Option<AnswerStore> = None;
+
+    for day in days {
+        if store.as_ref().map(|s| s.year) != Some(day.year) {
+            if let Some(store) = store.take().filter(|_| record) {
+                store.save()?;
+            }
+            store = Some(AnswerStore::load(day.year)?);
+        }
+        let store = store.as_mut().unwrap();
+
+        let mut inputs: Vec<String> = store.inputs(day.day).map(str::to_string).collect();
+        if !inputs.iter().any(|input| input == REAL_INPUT) {
+            inputs.insert(0, REAL_INPUT.to_string());
+        }
+
+        for input in inputs {
+            let source = if input == REAL_INPUT {
+                Source::Real
+            } else {
+                Source::Path(input.clone())
+            };
+            let parsed = runner::load(day, &source);
+            for part in Part::BOTH {
+                let expected = store
+                    .expected(day.day, &input)
+                    .and_then(|e| e.get(part))
+                    .map(str::to_string);
+                let (actual, unsolved) = match &parsed {
+                    Ok(parsed) => match runner::run_part(day, parsed.as_ref(), part) {
+                        Ok(answer) => (Ok(answer.to_string()), false),
+                        Err(e) => (Err(e.to_string()), matches!(e, Error::Unsolved)),
+                    },
+                    Err(e) => (Err(e.to_string()), false),
+                };
+
+                let status = match (&actual, &expected) {
+                    (Err(_), _) if unsolved => Status::Unsolved,
+                    (Err(_), _) => Status::Error,
+                    (Ok(answer), _) if record => {
+                        store.record(day.day, &input, part, answer.clone());
+                        Status::Recorded
+                    }
+                    (Ok(_), None) => Status::Missing,
+                    (Ok(answer), Some(expected)) if answer == expected => Status::Pass,
+                    (Ok(_), Some(_)) => Status::Fail,
+                };
+
+                checks.push(Check {
+                    year: day.year,
+                    day: day.day,
+                    input: input.clone(),
+                    part,
+                    expected,
+                    actual,
+                    status,
+                });
+            }
+        }
+    }
+
+    if let Some(store) = store.filter(|_| record) {
+        store.save()?;
+    }
+    Ok(checks)
+}
+
+pub fn print_table(checks: &[Check]) {
+    let rows: Vec<[String; 7]> = checks
+        .iter()
+        .map(|c| {
+            [
+                c.year.to_string(),
+                c.day.to_string(),
+                c.input.clone(),
+                c.part.number().to_string(),
+                c.expected.clone().unwrap_or_else(|| "-".to_string()),
+                match &c.actual {
+                    Ok(answer) => answer.clone(),
+                    Err(e) => e.clone(),
+                },
+                c.status.to_string(),
+            ]
+        })
+        .collect();
+    let header = [
+        "Year", "Day", "Input", "Part", "Expected", "Actual", "Status",
+    ];
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let print_row = |cells: &[&str]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        outln!("{}", line.join("  ").trim_end());
+    };
+    print_row(&header);
+    for row in &rows {
+        print_row(&row.each_ref().map(String::as_str));
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    outln!(
+        "\n{} passed, {} failed, {} missing, {} errors, {} recorded, {} unsolved",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::Error),
+        count(Status::Recorded),
+        count(Status::Unsolved),
+    );
+}
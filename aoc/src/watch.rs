@@ -0,0 +1,315 @@
+//! `aoc watch`: reruns a day whenever its code, its inputs, the shared
+//! library or the runner change. The runner has every day compiled in, so
+//! each round rebuilds it with cargo and runs the fresh binary with
+//! `--format json`.
+
+use crate::input::{self, Source};
+use crate::outln;
+use crate::registry::{workspace_root, Day};
+use crate::runner::{Part, PartResult};
+use crate::samples;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+/// How long to wait for an editor to finish writing before rebuilding.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Modification times of every file under some directories, or of single
+/// files.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    /// Scans `roots` recursively, skipping build output and hidden files. A
+    /// root that is a file is recorded as it is.
+    pub fn take(roots: &[PathBuf]) -> Snapshot {
+        let mut files = BTreeMap::new();
+        for root in roots {
+            match std::fs::metadata(root) {
+                Ok(metadata) if metadata.is_file() => {
+                    if let Ok(modified) = metadata.modified() {
+                        files.insert(root.clone(), modified);
+                    }
+                }
+                _ => scan(root, &mut files),
+            }
+        }
+        Snapshot(files)
+    }
+
+    /// Files added, removed or modified since `earlier`.
+    pub fn changed_since(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let modified = self
+            .0
+            .iter()
+            .filter(|(path, time)| earlier.0.get(*path) != Some(time));
+        let removed = earlier
+            .0
+            .iter()
+            .filter(|(path, _)| !self.0.contains_key(*path));
+        modified
+            .chain(removed)
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+fn scan(dir: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        if name == "target" || name.to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            scan(&entry.path(), files);
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(entry.path(), modified);
+        }
+    }
+}
+
+/// How an answer compares with the previous round's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// There was no previous answer.
+    First,
+    Unchanged,
+    Was(String),
+}
+
+impl Change {
+    pub fn between(previous: Option<&str>, current: &str) -> Change {
+        match previous {
+            None => Change::First,
+            Some(previous) if previous == current => Change::Unchanged,
+            Some(previous) => Change::Was(previous.to_string()),
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::First => Ok(()),
+            Change::Unchanged => f.write_str("(unchanged)"),
+            Change::Was(previous) => write!(f, "(was {})", previous),
+        }
+    }
+}
+
+/// What one run of the fresh binary produced.
+struct Run {
+    answers: BTreeMap<u8, String>,
+    /// Whatever it printed on stderr, e.g. a day's error, and any stdout
+    /// lines that weren't answers.
+    errors: String,
+}
+
+fn run(binary: &Path, day: &Day, source: &Source, parts: &[Part]) -> io::Result<Run> {
+    let mut command = Command::new(binary);
+    command
+        .args(["run", &day.year.to_string(), &day.day.to_string()])
+        .args(source.args())
+        .args(["--format", "json", "--quiet"]);
+    if let [part] = parts {
+        command.args(["--part", &part.number().to_string()]);
+    }
+    let output = command.stdin(Stdio::null()).output()?;
+
+    let mut answers = BTreeMap::new();
+    let mut errors = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        match serde_json::from_str::<PartResult>(line) {
+            Ok(result) => {
+                answers.insert(result.part, result.answer);
+            }
+            // Stray output from a day shouldn't stop the watch
+            Err(_) if !line.trim().is_empty() => errors.push(line.to_string()),
+            Err(_) => {}
+        }
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.trim().is_empty() {
+        errors.push(stderr.trim().to_string());
+    }
+    Ok(Run {
+        answers,
+        errors: errors.join("\n"),
+    })
+}
+
+fn build() -> io::Result<bool> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .args(["build", "--release", "--quiet", "-p", "aoc"])
+        .current_dir(workspace_root())
+        .status()?;
+    Ok(status.success())
+}
+
+fn binary() -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root().join("target"))
+        .join("release")
+        .join(format!("aoc{}", std::env::consts::EXE_SUFFIX))
+}
+
+/// ANSI colouring, when writing to a terminal.
+struct Paint(bool);
+
+impl Paint {
+    fn green(&self, text: &str) -> String {
+        self.paint("32", text)
+    }
+
+    fn red(&self, text: &str) -> String {
+        self.paint("31", text)
+    }
+
+    fn yellow(&self, text: &str) -> String {
+        self.paint("33", text)
+    }
+
+    fn paint(&self, colour: &str, text: &str) -> String {
+        if self.0 {
+            format!("\x1b[{}m{}\x1b[0m", colour, text)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Rebuilds and reruns `parts` of `day` on `source`, and checks its samples,
+/// now and after every change, until interrupted.
+pub fn watch(day: &Day, source: &Source, parts: &[Part], colour: bool) -> io::Result<()> {
+    let root = workspace_root();
+    let mut roots = vec![day.path(), root.join("aoc-core"), root.join("aoc/src")];
+    // An --input file elsewhere is watched on its own
+    let input = source
+        .path(day)
+        .filter(|path| !path.starts_with(day.path()));
+    roots.extend(input.clone());
+    let binary = binary();
+    let paint = Paint(colour);
+    let mut previous: BTreeMap<u8, String> = BTreeMap::new();
+    let mut snapshot = Snapshot::take(&roots);
+
+    match &input {
+        Some(input) => outln!(
+            "Watching {}, {}, aoc-core and aoc/src; press Ctrl-C to stop",
+            day.path().display(),
+            input.display()
+        ),
+        None => outln!(
+            "Watching {}, aoc-core and aoc/src; press Ctrl-C to stop",
+            day.path().display()
+        ),
+    }
+    loop {
+        if build()? {
+            let run = run(&binary, day, source, parts)?;
+            outln!("--- {} day {} ({}) ---", day.year, day.day, source);
+            for &part in parts {
+                match run.answers.get(&part.number()) {
+                    Some(answer) => {
+                        let change = Change::between(
+                            previous.get(&part.number()).map(String::as_str),
+                            answer,
+                        );
+                        match change {
+                            Change::First => outln!("Part {}: {}", part, answer),
+                            Change::Unchanged => outln!("Part {}: {} {}", part, answer, change),
+                            Change::Was(_) => outln!(
+                                "Part {}: {} {}",
+                                part,
+                                answer,
+                                paint.yellow(&change.to_string())
+                            ),
+                        }
+                    }
+                    None => outln!("Part {}: {}", part, paint.red("no answer")),
+                }
+            }
+            if !run.errors.is_empty() {
+                outln!("{}", paint.red(&run.errors));
+            }
+            previous.extend(run.answers);
+
+            check_samples(&binary, day, parts, &paint)?;
+        } else {
+            outln!("{}", paint.red("build failed"));
+        }
+
+        let changed = loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = Snapshot::take(&roots);
+            let changed = current.changed_since(&snapshot);
+            if !changed.is_empty() {
+                thread::sleep(SETTLE_TIME);
+                snapshot = Snapshot::take(&roots);
+                break changed;
+            }
+        };
+        let names: Vec<String> = changed
+            .iter()
+            .map(|path| {
+                path.strip_prefix(root)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        outln!("\nChanged: {}", names.join(", "));
+    }
+}
+
+/// Runs every sample that has a sidecar and says whether its answers still
+/// match.
+fn check_samples(binary: &Path, day: &Day, parts: &[Part], paint: &Paint) -> io::Result<()> {
+    let mut results = Vec::new();
+    for sample in input::samples(day) {
+        let Ok(expected) = samples::expected(day, &sample.label()) else {
+            continue;
+        };
+        let run = run(binary, day, &sample, parts)?;
+        let mut failures = Vec::new();
+        for &part in parts {
+            let Some(expected) = expected.get(part) else {
+                continue;
+            };
+            match run.answers.get(&part.number()) {
+                Some(answer) if answer == expected => {}
+                Some(answer) => failures.push(format!(
+                    "part {}: expected {}, got {}",
+                    part, expected, answer
+                )),
+                None => failures.push(format!(
+                    "part {}: expected {}, got an error",
+                    part, expected
+                )),
+            }
+        }
+        results.push(if failures.is_empty() {
+            format!("{} {}", sample, paint.green("pass"))
+        } else {
+            format!("{} {} ({})", sample, paint.red("FAIL"), failures.join("; "))
+        });
+    }
+    if !results.is_empty() {
+        outln!("Samples: {}", results.join(", "));
+    }
+    Ok(())
+}
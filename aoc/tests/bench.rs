@@ -0,0 +1,71 @@
+use aoc::bench::{compare, DayBench, Report, Stats};
+
+fn stats(median_ns: u64) -> Stats {
+    Stats {
+        min_ns: median_ns / 2,
+        median_ns,
+        max_ns: median_ns * 2,
+    }
+}
+
+fn day(day: u8, [parse, part_one, part_two]: [u64; 3]) -> DayBench {
+    DayBench {
+        year: 2024,
+        day,
+        input: "input".to_string(),
+        parse: stats(parse),
+        part_one: stats(part_one),
+        part_two: stats(part_two),
+    }
+}
+
+fn report(days: Vec<DayBench>) -> Report {
+    Report {
+        runs: 10,
+        warmup: 2,
+        days,
+    }
+}
+
+#[test]
+fn stats_take_the_middle_of_the_sorted_samples() {
+    let odd = Stats::from_samples(vec![30, 10, 50, 20, 40]);
+    assert_eq!((odd.min_ns, odd.median_ns, odd.max_ns), (10, 30, 50));
+    let even = Stats::from_samples(vec![40, 10, 30, 20]);
+    assert_eq!((even.min_ns, even.median_ns, even.max_ns), (10, 25, 40));
+    let one = Stats::from_samples(vec![7]);
+    assert_eq!((one.min_ns, one.median_ns, one.max_ns), (7, 7, 7));
+}
+
+#[test]
+fn only_stages_slower_than_the_threshold_regress() {
+    let before = report(vec![day(1, [100, 1000, 1000]), day(2, [100, 100, 100])]);
+    let after = report(vec![
+        // Part one 20% slower, part two 5% slower
+        day(1, [100, 1200, 1050]),
+        // Everything faster
+        day(2, [50, 90, 10]),
+    ]);
+
+    let regressions = compare(&before, &after, 10.0);
+    assert_eq!(regressions.len(), 1);
+    let regression = &regressions[0];
+    assert_eq!((regression.day, regression.stage), (1, "part one"));
+    assert_eq!((regression.before_ns, regression.after_ns), (1000, 1200));
+    assert!((regression.percent() - 20.0).abs() < 1e-9);
+
+    assert_eq!(compare(&before, &after, 4.0).len(), 2);
+    assert!(compare(&before, &after, 25.0).is_empty());
+}
+
+#[test]
+fn days_without_a_baseline_are_not_compared() {
+    let before = report(vec![day(1, [0, 100, 100])]);
+    let after = report(vec![day(1, [500, 100, 100]), day(3, [1000, 1000, 1000])]);
+    // A zero median can't be compared, and day 3 is new
+    assert!(compare(&before, &after, 10.0).is_empty());
+
+    let mut sample = day(1, [100, 100, 100]);
+    sample.input = "sample".to_string();
+    assert!(compare(&report(vec![sample]), &after, 10.0).is_empty());
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use aoc::cache::InputCache;
+use aoc::remote::{self, Backend, HttpBackend, RemoteError};
+use common::StubServer;
+use std::time::{Duration, Instant};
+
+#[test]
+fn fetches_on_miss_and_never_refetches() {
+    let server = StubServer::start(vec![(200, "1 2 3\n")]);
+    let dir = tempfile::tempdir().unwrap();
+    let config = server.config(dir.path());
+    let cache = InputCache::from_config(&config);
+    let backend = HttpBackend::new(&config);
+
+    assert_eq!(cache.get_or_fetch(2024, 9, &backend).unwrap(), "1 2 3\n");
+    assert_eq!(cache.get_or_fetch(2024, 9, &backend).unwrap(), "1 2 3\n");
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("2024/9/input")).unwrap(),
+        "1 2 3\n"
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2024/day/9/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-token"));
+}
+
+#[test]
+fn missing_token_fails_without_a_request() {
+    let server = StubServer::start(vec![(200, "unused")]);
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = server.config(dir.path());
+    config.session = Some("  ".to_string());
+    let cache = InputCache::from_config(&config);
+
+    let err = cache
+        .get_or_fetch(2024, 9, &HttpBackend::new(&config))
+        .unwrap_err();
+    assert!(matches!(err, RemoteError::MissingToken), "{:?}", err);
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn rejected_token_is_reported_as_expired() {
+    let server = StubServer::start(vec![(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    )]);
+    let dir = tempfile::tempdir().unwrap();
+    let config = server.config(dir.path());
+    let cache = InputCache::from_config(&config);
+
+    let err = cache
+        .get_or_fetch(2024, 9, &HttpBackend::new(&config))
+        .unwrap_err();
+    assert!(matches!(err, RemoteError::ExpiredToken), "{:?}", err);
+    assert!(!cache.path(2024, 9).exists());
+}
+
+#[test]
+fn locked_puzzle_is_not_found() {
+    let server = StubServer::start(vec![(404, "Not Found")]);
+    let dir = tempfile::tempdir().unwrap();
+    let config = server.config(dir.path());
+
+    let err = HttpBackend::new(&config).fetch_input(2024, 25).unwrap_err();
+    assert!(matches!(err, RemoteError::NotFound), "{:?}", err);
+}
+
+#[test]
+fn requests_are_rate_limited() {
+    let server = StubServer::start(vec![(200, "a"), (200, "b")]);
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = server.config(dir.path());
+    config.min_request_interval = Some(0.3);
+    let cache = InputCache::from_config(&config);
+    let backend = HttpBackend::new(&config);
+
+    let start = Instant::now();
+    cache.get_or_fetch(2024, 1, &backend).unwrap();
+    cache.get_or_fetch(2024, 2, &backend).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn offline_backend_only_serves_the_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = aoc::config::Config {
+        cache_dir: Some(dir.path().to_path_buf()),
+        offline: true,
+        ..Default::default()
+    };
+    let cache = InputCache::from_config(&config);
+    let backend = remote::backend(&config);
+
+    let err = cache.get_or_fetch(2024, 3, backend.as_ref()).unwrap_err();
+    assert!(matches!(err, RemoteError::Offline), "{:?}", err);
+
+    std::fs::create_dir_all(dir.path().join("2024/3")).unwrap();
+    std::fs::write(dir.path().join("2024/3/input"), "cached").unwrap();
+    assert_eq!(
+        cache.get_or_fetch(2024, 3, backend.as_ref()).unwrap(),
+        "cached"
+    );
+}
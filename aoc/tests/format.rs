@@ -0,0 +1,96 @@
+use std::process::{Command, Stdio};
+
+fn aoc(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn json_prints_one_object_per_part() {
+    let stdout = aoc(&["run", "2024", "2", "--sample", "--format", "json"]);
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(lines.len(), 2);
+    for (line, (part, answer)) in lines.iter().zip([(1, "2"), (2, "4")]) {
+        assert_eq!(line["year"], 2024);
+        assert_eq!(line["day"], 2);
+        assert_eq!(line["part"], part);
+        assert_eq!(line["answer"], answer);
+        assert_eq!(line["input"], "sample");
+        assert!(line["elapsed_ns"].is_u64());
+    }
+}
+
+#[test]
+fn csv_prints_a_header_and_one_row_per_part() {
+    let stdout = aoc(&["--format", "csv", "run", "2024", "2", "--sample"]);
+    let rows: Vec<Vec<&str>> = stdout.lines().map(|l| l.split(',').collect()).collect();
+
+    assert_eq!(
+        rows[0],
+        ["year", "day", "part", "answer", "elapsed_ns", "input"]
+    );
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[1][..4], ["2024", "2", "1", "2"]);
+    assert_eq!(rows[2][..4], ["2024", "2", "2", "4"]);
+}
+
+#[test]
+fn parallel_runs_report_in_order() {
+    let answers = |jobs: &str| -> Vec<(u64, u64, String)> {
+        aoc(&[
+            "run", "2024", "--all", "--sample", "--jobs", jobs, "--format", "json",
+        ])
+        .lines()
+        .map(|line| {
+            let line: serde_json::Value = serde_json::from_str(line).unwrap();
+            (
+                line["day"].as_u64().unwrap(),
+                line["part"].as_u64().unwrap(),
+                line["answer"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+    };
+
+    let sequential = answers("1");
+    assert!(sequential
+        .windows(2)
+        .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+    // Days added later may not be solved yet, so only these must be there
+    for day in [1, 2, 3, 4, 5, 6, 7, 8] {
+        for part in [1, 2] {
+            assert!(
+                sequential.iter().any(|a| (a.0, a.1) == (day, part)),
+                "no answer for day {} part {}",
+                day,
+                part
+            );
+        }
+    }
+    assert_eq!(answers("8"), sequential);
+}
+
+#[test]
+fn a_closed_pipe_ends_the_output_quietly() {
+    for format in ["text", "json", "csv"] {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["--format", format, "run", "2024", "--all", "--sample"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        // Like `| head -0`: the reader is gone before anything is printed
+        drop(child.stdout.take());
+        let output = child.wait_with_output().unwrap();
+        assert!(output.status.success(), "{}: {:?}", format, output);
+        assert_eq!(String::from_utf8_lossy(&output.stderr), "", "{}", format);
+    }
+}
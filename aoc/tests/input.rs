@@ -0,0 +1,104 @@
+use aoc::input::Source;
+use aoc::registry::Day;
+use aoc::vault::{self, Key};
+use aoc_core::Error;
+use std::fs;
+use std::path::Path;
+
+/// A day whose directory is `dir`, which `Day::path` uses as it is because
+/// it's absolute.
+fn day_in(dir: &Path) -> Day {
+    Day {
+        year: 2024,
+        day: 1,
+        dir: Box::leak(dir.to_str().unwrap().to_string().into_boxed_str()),
+        solution: &y2024_day1::Day1,
+    }
+}
+
+fn io_error_path(result: aoc_core::Result<String>) -> std::path::PathBuf {
+    match result {
+        Err(Error::Io { path, .. }) => path,
+        other => panic!("expected an I/O error, got {:?}", other),
+    }
+}
+
+#[test]
+fn explicit_paths_prefer_the_day_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let elsewhere = tempfile::tempdir().unwrap();
+    let day = day_in(dir.path());
+    fs::write(dir.path().join("big"), "in the day").unwrap();
+    fs::write(dir.path().join("sample"), "sample one").unwrap();
+    fs::write(elsewhere.path().join("big"), "elsewhere").unwrap();
+
+    assert_eq!(
+        Source::Path("big".to_string()).read(&day).unwrap(),
+        "in the day"
+    );
+    let outside = elsewhere.path().join("big");
+    assert_eq!(
+        Source::Path(outside.display().to_string())
+            .read(&day)
+            .unwrap(),
+        "elsewhere"
+    );
+    assert_eq!(Source::Sample(1).read(&day).unwrap(), "sample one");
+
+    assert_eq!(
+        io_error_path(Source::Sample(2).read(&day)),
+        dir.path().join("sample2")
+    );
+    let missing = elsewhere.path().join("nope");
+    assert_eq!(
+        io_error_path(Source::Path(missing.display().to_string()).read(&day)),
+        missing
+    );
+}
+
+/// Everything that reads the config is in one test, as it's set through the
+/// environment, which every test in this file shares.
+#[test]
+fn real_inputs_come_from_the_plain_file_then_the_sealed_one_then_the_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let home = tempfile::tempdir().unwrap();
+    let day = day_in(dir.path());
+    let key_file = home.path().join("input.key");
+    let cache = home.path().join("cache");
+    let config = home.path().join("config.toml");
+    fs::write(&config, "offline = true\n").unwrap();
+    std::env::set_var("AOC_CONFIG", &config);
+    std::env::set_var("AOC_KEY_FILE", &key_file);
+    std::env::set_var("AOC_CACHE_DIR", &cache);
+
+    // Nothing anywhere, and offline: the error names the cache entry
+    assert_eq!(
+        io_error_path(Source::Real.read(&day)),
+        cache.join("2024/1/input")
+    );
+
+    fs::create_dir_all(cache.join("2024/1")).unwrap();
+    fs::write(cache.join("2024/1/input"), "cached").unwrap();
+    assert_eq!(Source::Real.read(&day).unwrap(), "cached");
+
+    let (key, _) = Key::load_or_generate(&key_file).unwrap();
+    let input = dir.path().join("input");
+    fs::write(&input, "sealed").unwrap();
+    vault::encrypt_file(&key, &input).unwrap();
+    fs::remove_file(&input).unwrap();
+    assert_eq!(Source::Real.read(&day).unwrap(), "sealed");
+
+    fs::write(&input, "plain").unwrap();
+    assert_eq!(Source::Real.read(&day).unwrap(), "plain");
+
+    // Without the key, a sealed input is an error rather than a cache lookup
+    fs::remove_file(&input).unwrap();
+    fs::remove_file(&key_file).unwrap();
+    match Source::Real.read(&day) {
+        Err(Error::Io { path, source }) => {
+            assert_eq!(path, dir.path().join("input.enc"));
+            assert!(source.to_string().contains("input.key"), "{}", source);
+        }
+        other => panic!("expected an I/O error, got {:?}", other),
+    }
+}
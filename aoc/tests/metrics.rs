@@ -0,0 +1,44 @@
+use aoc::metrics::{compare, Numeric};
+use aoc_core::Error;
+use y2024_day1::BigInt;
+
+#[test]
+fn metrics_compare_the_sample_columns() {
+    let record = compare("3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n", 1, 2, Numeric::Big).unwrap();
+    assert_eq!(record.rows, 6);
+    assert_eq!(record.squared_distance, "35");
+    let close = |actual: Option<f64>, expected: f64| {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} != {}",
+            actual,
+            expected
+        );
+    };
+    close(record.kendall_tau, -1.0 / 12.0);
+    close(record.spearman, -3.0 / 31.0);
+    close(record.jaccard, 0.5);
+    // The mean distance, as the columns are the same length
+    close(record.earth_movers_distance, 11.0 / 6.0);
+
+    assert_eq!(
+        compare("1 2\n", 1, 3, Numeric::Big)
+            .unwrap_err()
+            .to_string(),
+        "no column 3 in an input with 2 columns"
+    );
+}
+
+#[test]
+fn metrics_read_values_as_the_chosen_type() {
+    let large = format!("0 {}\n", u64::MAX);
+    let squared = |numeric| compare(&large, 1, 2, numeric).map(|record| record.squared_distance);
+    assert!(matches!(squared(Numeric::I64), Err(Error::Parse { .. })));
+    assert!(matches!(squared(Numeric::U64), Err(Error::Puzzle(_))));
+    assert!(matches!(squared(Numeric::I128), Err(Error::Puzzle(_))));
+    assert_eq!(
+        squared(Numeric::Big).unwrap(),
+        (BigInt::from(u64::MAX) * u64::MAX).to_string()
+    );
+}
@@ -0,0 +1,25 @@
+use aoc::registry;
+use std::collections::HashSet;
+
+#[test]
+fn days_are_unique_and_ordered_by_year_and_day() {
+    let keys: Vec<(u16, u8)> = registry::DAYS.iter().map(|d| (d.year, d.day)).collect();
+    assert!(keys.windows(2).all(|w| w[0] < w[1]), "{:?}", keys);
+
+    let dirs: HashSet<&str> = registry::DAYS.iter().map(|d| d.dir).collect();
+    assert_eq!(dirs.len(), registry::DAYS.len());
+    for day in registry::DAYS {
+        assert_eq!(day.dir, format!("{}/rust/day{}", day.year, day.day));
+        assert!(day.path().join("Cargo.toml").is_file(), "{}", day.dir);
+    }
+}
+
+#[test]
+fn status_covers_every_registered_day_of_a_year() {
+    assert!(registry::years().contains(&2024));
+    let status = registry::status(Some(2024)).unwrap();
+    assert_eq!(status.len(), registry::days_in_year(2024).count());
+    assert!(status.iter().all(|s| s.year == 2024));
+
+    assert!(registry::status(Some(1999)).unwrap().is_empty());
+}
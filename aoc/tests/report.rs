@@ -0,0 +1,106 @@
+use aoc::bench::{DayBench, Report, Stats};
+use aoc::input::Source;
+use aoc::registry;
+use aoc::report;
+use aoc::runner::Part;
+use aoc::verify::{Check, Status};
+
+fn check(day: u8, part: Part, actual: Result<&str, &str>, expected: &str, status: Status) -> Check {
+    Check {
+        year: 2024,
+        day,
+        input: "input".to_string(),
+        part,
+        expected: Some(expected.to_string()),
+        actual: actual.map(str::to_string).map_err(str::to_string),
+        status,
+    }
+}
+
+#[test]
+fn days_with_pictures_draw_their_samples() {
+    let day = |n| registry::find(2024, n).unwrap();
+    assert!(report::visualize(day(1), &Source::Sample(1)).is_none());
+
+    let graph = report::visualize(day(5), &Source::Sample(1))
+        .unwrap()
+        .unwrap();
+    // The sample has 7 pages and 21 rules
+    assert_eq!(graph[0].svg.matches("<circle").count(), 7);
+    assert_eq!(graph[0].svg.matches("<line").count(), 21);
+
+    let patrol = report::visualize(day(6), &Source::Sample(1))
+        .unwrap()
+        .unwrap();
+    assert!(patrol[0].svg.contains(r#"viewBox="0 0 10 10""#));
+    assert_eq!(patrol[0].svg.matches("<polyline").count(), 1);
+
+    let antinodes = report::visualize(day(8), &Source::Sample(1))
+        .unwrap()
+        .unwrap();
+    assert_eq!(antinodes.len(), 2);
+    // 14 antinodes for part one, 34 with harmonics, on top of the background
+    assert_eq!(antinodes[0].svg.matches("<rect").count(), 1 + 14);
+    assert_eq!(antinodes[1].svg.matches("<rect").count(), 1 + 34);
+
+    assert!(
+        report::visualize(day(6), &Source::Path("missing".to_string()))
+            .unwrap()
+            .is_err()
+    );
+}
+
+#[test]
+fn graphs_leave_out_self_loops() {
+    let svg = report::graph_svg(&[(1, 2), (2, 2), (2, 3)]);
+    assert_eq!(svg.matches("<circle").count(), 3);
+    assert_eq!(svg.matches("<line").count(), 2);
+    assert!(!svg.contains("NaN"));
+
+    let svg = report::graph_svg(&[(7, 7)]);
+    assert_eq!(svg.matches("<circle").count(), 1);
+    assert_eq!(svg.matches("<line").count(), 0);
+}
+
+#[test]
+fn page_shows_answers_status_and_timings() {
+    let checks = [
+        check(1, Part::One, Ok("11"), "11", Status::Pass),
+        check(1, Part::Two, Ok("<32>"), "31", Status::Fail),
+        check(2, Part::One, Err("no input"), "2", Status::Error),
+        check(2, Part::Two, Err("no input"), "4", Status::Error),
+    ];
+    let stats = |median_ns| Stats {
+        min_ns: median_ns,
+        median_ns,
+        max_ns: median_ns,
+    };
+    let bench = Report {
+        runs: 10,
+        warmup: 3,
+        days: vec![DayBench {
+            year: 2024,
+            day: 1,
+            input: "input".to_string(),
+            parse: stats(40_000),
+            part_one: stats(2_000_000),
+            part_two: stats(1_500_000_000),
+        }],
+    };
+    let day5 = registry::find(2024, 5).unwrap();
+    let visuals = [(day5, Err("no input".to_string()))];
+
+    let html = report::render(&checks, Some(&bench), &visuals);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains(r#"<td class="pass" title="pass">11</td>"#));
+    assert!(html.contains(r#"<td class="fail" title="expected 31">&lt;32&gt;</td>"#));
+    assert!(html.contains(r#"<td class="error" title="no input">error</td>"#));
+    assert!(html.contains("1 passed, 1 failed, 0 missing, 2 errors"));
+    assert!(html.contains("Median of 10 runs"));
+    assert!(html.contains("40.0µs") && html.contains("1.50s"));
+    assert!(html.contains("<h3>2024 day 5</h3>\n<p>Not drawn: no input</p>"));
+
+    let html = report::render(&checks, None, &[]);
+    assert!(html.contains("No timings yet"));
+    assert!(!html.contains("Visualizations"));
+}
@@ -0,0 +1,39 @@
+use aoc::safety::RuleArgs;
+use std::fs;
+use y2024_day2::{SafetyRules, Trend};
+
+#[test]
+fn flags_override_the_rules_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rules.toml");
+    fs::write(
+        &path,
+        "max_step = 5\ndirection = \"any\"\nplateaus = true\n",
+    )
+    .unwrap();
+
+    let args = RuleArgs {
+        rules: Some(path.clone()),
+        plateaus: Some(false),
+        ..RuleArgs::default()
+    };
+    assert_eq!(
+        args.rules().unwrap(),
+        SafetyRules {
+            min_step: 1,
+            max_step: 5,
+            direction: Trend::Any,
+            plateaus: false,
+            min_length: 2,
+        }
+    );
+    assert_eq!(RuleArgs::default().rules().unwrap(), SafetyRules::default());
+
+    fs::write(&path, "direction = \"sideways\"\n").unwrap();
+    let err = args.rules().unwrap_err().to_string();
+    assert!(err.starts_with("unknown direction \"sideways\""), "{}", err);
+
+    fs::write(&path, "max_step = 0\n").unwrap();
+    let err = args.rules().unwrap_err().to_string();
+    assert_eq!(err, "the minimum step (1) is larger than the maximum (0)");
+}
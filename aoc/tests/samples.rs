@@ -0,0 +1,3 @@
+//! One test per sample input with a `.answers` sidecar; see `aoc::samples`.
+
+include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
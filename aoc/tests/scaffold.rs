@@ -0,0 +1,86 @@
+use aoc::scaffold::{add_day, add_dependency};
+
+const MANIFEST: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+aoc-core = { path = "../aoc-core" }
+y2024-day1 = { path = "../2024/rust/day1" }
+y2024-day2 = { path = "../2024/rust/day2" }
+serde = "1"
+
+[dev-dependencies]
+tempfile = "3"
+"#;
+
+const REGISTRY: &str = "use crate::Day;
+
+pub const DAYS: &[Day] = &[
+    day!(2023, 25, y2023_day25::Day25),
+    day!(2024, 1, y2024_day1::Day1),
+    day!(2024, 3, y2024_day3::Day3),
+];
+
+pub fn find() {}
+";
+
+#[test]
+fn dependencies_go_after_the_last_day_crate() {
+    let manifest = add_dependency(MANIFEST, 2024, 9).unwrap();
+    assert!(manifest.contains(
+        "y2024-day2 = { path = \"../2024/rust/day2\" }\n\
+         y2024-day9 = { path = \"../2024/rust/day9\" }\n\
+         serde = \"1\"\n"
+    ));
+    assert!(manifest.ends_with("[dev-dependencies]\ntempfile = \"3\"\n"));
+}
+
+#[test]
+fn dependencies_go_at_the_end_of_the_table_without_day_crates() {
+    let manifest = "[dependencies]\nserde = \"1\"\n\n[dev-dependencies]\ntempfile = \"3\"\n";
+    assert_eq!(
+        add_dependency(manifest, 2023, 1).unwrap(),
+        "[dependencies]\nserde = \"1\"\ny2023-day1 = { path = \"../2023/rust/day1\" }\n\n\
+         [dev-dependencies]\ntempfile = \"3\"\n"
+    );
+
+    let err = add_dependency("[package]\nname = \"aoc\"\n", 2024, 9).unwrap_err();
+    assert_eq!(err.to_string(), "no [dependencies] table in aoc/Cargo.toml");
+}
+
+#[test]
+fn registered_dependencies_are_rejected() {
+    let err = add_dependency(MANIFEST, 2024, 2).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "y2024-day2 is already a dependency in aoc/Cargo.toml"
+    );
+    // A longer name with the same prefix is a different day
+    assert!(add_dependency(MANIFEST, 2024, 21).is_ok());
+}
+
+#[test]
+fn days_are_registered_in_order() {
+    let registry = add_day(REGISTRY, 2024, 2).unwrap();
+    assert!(registry.contains(
+        "    day!(2024, 1, y2024_day1::Day1),\n\
+         \x20   day!(2024, 2, y2024_day2::Day2),\n\
+         \x20   day!(2024, 3, y2024_day3::Day3),\n"
+    ));
+
+    let registry = add_day(REGISTRY, 2025, 1).unwrap();
+    assert!(registry.contains("    day!(2025, 1, y2025_day1::Day1),\n];\n"));
+    let registry = add_day(REGISTRY, 2015, 1).unwrap();
+    assert!(registry.contains("&[\n    day!(2015, 1, y2015_day1::Day1),\n"));
+}
+
+#[test]
+fn registered_days_are_rejected() {
+    let err = add_day(REGISTRY, 2024, 3).unwrap_err();
+    assert_eq!(err.to_string(), "2024 day 3 is already in registry.rs");
+
+    let err = add_day("pub fn find() {}\n", 2024, 9).unwrap_err();
+    assert_eq!(err.to_string(), "no DAYS table in registry.rs");
+    let err = add_day("pub const DAYS: &[Day] = &[\n", 2024, 9).unwrap_err();
+    assert_eq!(err.to_string(), "unterminated DAYS table in registry.rs");
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use aoc::answers::{AnswerStore, Hint, Rejected};
+use aoc::registry;
+use aoc::remote::HttpBackend;
+use aoc::runner::Part;
+use aoc::submit::{self, Outcome, Submission};
+use common::StubServer;
+
+const RIGHT: &str = "<article><p>That's the right answer!  You are one gold star closer to saving your vacation.</p></article>";
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article>";
+const TOO_LOW: &str =
+    "<article><p>That's not the right answer; your answer is too low.</p></article>";
+const WRONG: &str =
+    "<article><p>That's not the right answer.  If you're stuck, ask for help.</p></article>";
+const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait. <a href=\"/2024/day/7\">[Return to Day 7]</a></p></article>";
+const WRONG_LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+#[test]
+fn parses_every_kind_of_response() {
+    assert_eq!(Outcome::parse(RIGHT), Outcome::Correct);
+    assert_eq!(Outcome::parse(TOO_HIGH), Outcome::TooHigh);
+    assert_eq!(Outcome::parse(TOO_LOW), Outcome::TooLow);
+    assert_eq!(Outcome::parse(WRONG), Outcome::Incorrect);
+    assert_eq!(
+        Outcome::parse(TOO_RECENT),
+        Outcome::RateLimited {
+            wait: Some("37s".to_string())
+        }
+    );
+    assert_eq!(Outcome::parse(WRONG_LEVEL), Outcome::WrongLevel);
+    assert_eq!(Outcome::parse("<html></html>"), Outcome::Unrecognized);
+}
+
+#[test]
+fn correct_answer_is_posted_and_recorded() {
+    let server = StubServer::start(vec![(200, RIGHT)]);
+    let dir = tempfile::tempdir().unwrap();
+    let backend = HttpBackend::new(&server.config(dir.path()));
+    let day = registry::find(2024, 7).unwrap();
+    let mut store = AnswerStore::default();
+
+    let submission = submit::submit(&mut store, &backend, day, Part::Two, "11387").unwrap();
+    assert_eq!(submission, Submission::Sent(Outcome::Correct));
+    assert_eq!(
+        store.expected(7, "input").unwrap().get(Part::Two),
+        Some("11387")
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2024/day/7/answer");
+    assert_eq!(requests[0].body, "level=2&answer=11387");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-token"));
+
+    // Solved parts are never submitted again
+    let submission = submit::submit(&mut store, &backend, day, Part::Two, "11387").unwrap();
+    assert!(matches!(submission, Submission::AlreadySolved { .. }));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn wrong_answers_are_remembered_and_refused() {
+    let server = StubServer::start(vec![(200, TOO_HIGH), (200, WRONG)]);
+    let dir = tempfile::tempdir().unwrap();
+    let backend = HttpBackend::new(&server.config(dir.path()));
+    let day = registry::find(2024, 7).unwrap();
+    let mut store = AnswerStore::default();
+
+    let too_high = Rejected {
+        answer: "500".to_string(),
+        hint: Some(Hint::TooHigh),
+    };
+    assert_eq!(
+        submit::submit(&mut store, &backend, day, Part::One, "500").unwrap(),
+        Submission::Sent(Outcome::TooHigh)
+    );
+    assert_eq!(
+        store.expected(7, "input").unwrap().rejected(Part::One),
+        std::slice::from_ref(&too_high)
+    );
+
+    // The same answer, or a larger one, is known to be wrong without asking
+    for answer in ["500", "501"] {
+        assert_eq!(
+            submit::submit(&mut store, &backend, day, Part::One, answer).unwrap(),
+            Submission::KnownWrong(too_high.clone())
+        );
+    }
+    assert_eq!(server.requests().len(), 1);
+
+    assert_eq!(
+        submit::submit(&mut store, &backend, day, Part::One, "499").unwrap(),
+        Submission::Sent(Outcome::Incorrect)
+    );
+    assert_eq!(server.requests().len(), 2);
+    assert!(store.expected(7, "input").unwrap().get(Part::One).is_none());
+}
+
+#[test]
+fn rate_limited_answers_are_not_recorded() {
+    let server = StubServer::start(vec![(200, TOO_RECENT)]);
+    let dir = tempfile::tempdir().unwrap();
+    let backend = HttpBackend::new(&server.config(dir.path()));
+    let day = registry::find(2024, 7).unwrap();
+    let mut store = AnswerStore::default();
+
+    let submission = submit::submit(&mut store, &backend, day, Part::One, "42").unwrap();
+    assert!(matches!(
+        submission,
+        Submission::Sent(Outcome::RateLimited { .. })
+    ));
+    assert!(store.expected(7, "input").is_none());
+}
@@ -0,0 +1,138 @@
+use aoc::answers::Expected;
+use aoc::bench::{DayBench, Stats};
+use aoc::registry;
+use aoc::runner::{Part, PartResult};
+use aoc::tui::{self, App, DayRow, Message, PartState, Request, Verdict};
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::Terminal;
+
+fn row(day: u8) -> DayRow {
+    DayRow {
+        day: registry::find(2024, day).unwrap(),
+        expected: Expected {
+            part1: Some("11".to_string()),
+            ..Expected::default()
+        },
+        bench: None,
+        parts: [PartState::NotRun, PartState::NotRun],
+    }
+}
+
+fn solved(part: Part, answer: &str) -> Message {
+    Message::Part {
+        row: 0,
+        part,
+        result: Ok(PartResult {
+            year: 2024,
+            day: 1,
+            part: part.number(),
+            answer: answer.to_string(),
+            elapsed_ns: 1_500_000,
+            input: "input".to_string(),
+        }),
+    }
+}
+
+#[test]
+fn keys_move_the_selection_and_request_runs() {
+    let mut app = App::new(vec![row(1), row(2)]);
+    app.handle_key(KeyCode::Up);
+    assert_eq!(app.selected, 0);
+    app.handle_key(KeyCode::Char('j'));
+    app.handle_key(KeyCode::Down);
+    assert_eq!(app.selected, 1);
+
+    assert_eq!(
+        app.handle_key(KeyCode::Char('2')),
+        [Request {
+            row: 1,
+            parts: vec![Part::Two]
+        }]
+    );
+    assert!(matches!(app.rows[1].state(Part::Two), PartState::Running));
+    // A part that is already running isn't started again
+    assert_eq!(
+        app.handle_key(KeyCode::Enter),
+        [Request {
+            row: 1,
+            parts: vec![Part::One]
+        }]
+    );
+    assert_eq!(
+        app.handle_key(KeyCode::Char('a')),
+        [Request {
+            row: 0,
+            parts: Part::BOTH.to_vec()
+        }]
+    );
+
+    assert!(!app.quit);
+    app.handle_key(KeyCode::Char('q'));
+    assert!(app.quit);
+}
+
+#[test]
+fn results_are_checked_against_known_answers() {
+    let mut app = App::new(vec![row(1)]);
+    assert_eq!(app.rows[0].verdict(Part::One), None);
+
+    app.apply(solved(Part::One, "11"));
+    assert_eq!(app.rows[0].verdict(Part::One), Some(Verdict::Pass));
+    app.apply(solved(Part::One, "12"));
+    assert_eq!(app.rows[0].verdict(Part::One), Some(Verdict::Fail));
+    app.apply(solved(Part::Two, "31"));
+    assert_eq!(app.rows[0].verdict(Part::Two), Some(Verdict::Unknown));
+
+    app.apply(Message::Part {
+        row: 0,
+        part: Part::Two,
+        result: Err("no input".to_string()),
+    });
+    assert!(matches!(app.rows[0].state(Part::Two), PartState::Failed(_)));
+    assert_eq!(
+        app.log.last().map(String::as_str),
+        Some("2024 day 1 part two: error: no input")
+    );
+}
+
+#[test]
+fn dashboard_shows_stars_answers_timings_and_output() {
+    let mut app = App::new(vec![row(1), row(2)]);
+    let stats = Stats {
+        min_ns: 1_000,
+        median_ns: 2_000,
+        max_ns: 3_000,
+    };
+    app.rows[0].bench = Some(DayBench {
+        year: 2024,
+        day: 1,
+        input: "input".to_string(),
+        parse: stats,
+        part_one: stats,
+        part_two: stats,
+    });
+    app.apply(solved(Part::One, "11"));
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+    terminal.draw(|frame| tui::draw(frame, &app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let lines: Vec<String> = (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        })
+        .collect();
+    let screen = lines.join("\n");
+
+    let day1 = lines.iter().find(|l| l.contains("│2024 1 ")).unwrap();
+    assert!(day1.contains(" * "), "{}", day1);
+    assert!(day1.contains("11 ✓"), "{}", day1);
+    assert!(day1.contains("2.0µs"), "{}", day1);
+    assert!(
+        screen.contains("2024 day 1 part one: 11 in 1.50ms"),
+        "{}",
+        screen
+    );
+}
@@ -0,0 +1,50 @@
+use aoc::vault::{self, Key};
+
+#[test]
+fn sealed_input_round_trips() {
+    let dir = tempfile::tempdir().unwrap();
+    let key_file = dir.path().join("input.key");
+    let (key, generated) = Key::load_or_generate(&key_file).unwrap();
+    assert!(generated);
+
+    let input = dir.path().join("input");
+    std::fs::write(&input, "3   4\n4   3\n").unwrap();
+    let sealed = vault::encrypt_file(&key, &input).unwrap();
+    assert_eq!(sealed, dir.path().join("input.enc"));
+    assert!(!std::fs::read(&sealed)
+        .unwrap()
+        .windows(5)
+        .any(|w| w == b"3   4"));
+
+    assert_eq!(vault::read(&sealed, &key_file).unwrap(), "3   4\n4   3\n");
+
+    std::fs::remove_file(&input).unwrap();
+    let (key, generated) = Key::load_or_generate(&key_file).unwrap();
+    assert!(!generated);
+    vault::decrypt_file(&key, &input).unwrap();
+    assert_eq!(std::fs::read_to_string(&input).unwrap(), "3   4\n4   3\n");
+}
+
+#[test]
+fn wrong_key_is_rejected() {
+    let sealed = vault::seal(&Key::generate(), b"secret");
+    let err = vault::open(&Key::generate(), &sealed).unwrap_err();
+    assert!(err.to_string().contains("wrong key"), "{}", err);
+}
+
+#[test]
+fn tampering_is_detected() {
+    let key = Key::generate();
+    let mut sealed = vault::seal(&key, b"secret");
+    *sealed.last_mut().unwrap() ^= 1;
+    assert!(vault::open(&key, &sealed).is_err());
+    assert!(vault::open(&key, b"plain text").is_err());
+}
+
+#[test]
+fn missing_key_file_names_the_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let key_file = dir.path().join("nope.key");
+    let err = vault::read(&dir.path().join("input.enc"), &key_file).unwrap_err();
+    assert!(err.to_string().contains("nope.key"), "{}", err);
+}
@@ -0,0 +1,74 @@
+use aoc::watch::{Change, Snapshot};
+use std::fs::{self, File};
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn snapshots_notice_added_modified_and_removed_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let roots = [dir.path().to_path_buf()];
+    fs::create_dir_all(dir.path().join("src")).unwrap();
+    fs::create_dir_all(dir.path().join("target")).unwrap();
+    fs::write(dir.path().join("src/lib.rs"), "").unwrap();
+    fs::write(dir.path().join("input"), "").unwrap();
+    let before = Snapshot::take(&roots);
+
+    // Build output and hidden files are ignored
+    fs::write(dir.path().join("target/out"), "").unwrap();
+    fs::write(dir.path().join(".lib.rs.swp"), "").unwrap();
+    assert!(Snapshot::take(&roots).changed_since(&before).is_empty());
+
+    let later = SystemTime::now() + Duration::from_secs(10);
+    File::options()
+        .write(true)
+        .open(dir.path().join("src/lib.rs"))
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+    fs::remove_file(dir.path().join("input")).unwrap();
+    fs::write(dir.path().join("sample"), "").unwrap();
+
+    let mut changed = Snapshot::take(&roots).changed_since(&before);
+    changed.sort();
+    assert_eq!(
+        changed,
+        ["input", "sample", "src/lib.rs"].map(|name| dir.path().join(name))
+    );
+}
+
+#[test]
+fn snapshots_watch_single_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("input.txt");
+    fs::write(&input, "1 2").unwrap();
+    fs::write(dir.path().join("other"), "").unwrap();
+    let roots = [input.clone()];
+    let before = Snapshot::take(&roots);
+
+    let later = SystemTime::now() + Duration::from_secs(10);
+    File::options()
+        .write(true)
+        .open(dir.path().join("other"))
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+    assert!(Snapshot::take(&roots).changed_since(&before).is_empty());
+
+    File::options()
+        .write(true)
+        .open(&input)
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+    assert_eq!(Snapshot::take(&roots).changed_since(&before), [input]);
+}
+
+#[test]
+fn answers_are_compared_with_the_previous_round() {
+    assert_eq!(Change::between(None, "41"), Change::First);
+    assert_eq!(Change::between(Some("41"), "41"), Change::Unchanged);
+    assert_eq!(
+        Change::between(Some("41"), "42"),
+        Change::Was("41".to_string())
+    );
+    assert_eq!(Change::Was("41".to_string()).to_string(), "(was 41)");
+}